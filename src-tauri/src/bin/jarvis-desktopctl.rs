@@ -0,0 +1,9 @@
+//! Command-line companion for headless queue operations.
+//!
+//! Links the app library and runs the jobs, pipelines, library and
+//! diagnostics code in this process, so output goes to this console and the
+//! state files are shared with the app.
+
+fn main() {
+    std::process::exit(app_lib::run_ctl(std::env::args().skip(1).collect()));
+}
@@ -0,0 +1,308 @@
+//! OS-specific process and shell helpers.
+//!
+//! Everything that shells out differently per platform (killing job process
+//! trees, revealing folders, locating a venv interpreter) lives here so the
+//! command handlers in `lib.rs` stay platform-neutral. Pure helpers take the
+//! OS name explicitly so they can be unit-tested on any host.
+
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Grace period between SIGTERM and SIGKILL when canceling a job.
+pub const KILL_GRACE: Duration = Duration::from_secs(3);
+
+/// Puts the spawned child in its own process group on Unix so the whole
+/// tree can be signaled at once. No-op on Windows, where `taskkill /T` walks
+/// the tree itself.
+pub fn isolate_process_group(cmd: &mut Command) {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        cmd.process_group(0);
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = cmd;
+    }
+}
+
+/// Caps the data segment of the spawned child at `limit_bytes` on Unix. The
+/// rlimit is inherited, so every process the child forks gets the same
+/// per-process cap and allocations beyond it fail inside that process. The
+/// hard limit is never raised; if the limit cannot be read or set the child
+/// starts without it. No-op on Windows, which uses [`MemoryJob`] instead.
+pub fn limit_child_memory(cmd: &mut Command, limit_bytes: u64) {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        // SAFETY: getrlimit/setrlimit are async-signal-safe and the closure
+        // touches no memory shared with the parent.
+        unsafe {
+            cmd.pre_exec(move || {
+                let mut current = libc::rlimit {
+                    rlim_cur: 0,
+                    rlim_max: 0,
+                };
+                if libc::getrlimit(libc::RLIMIT_DATA, &mut current) == 0 {
+                    let capped = libc::rlimit {
+                        rlim_cur: (limit_bytes as libc::rlim_t).min(current.rlim_max),
+                        rlim_max: current.rlim_max,
+                    };
+                    libc::setrlimit(libc::RLIMIT_DATA, &capped);
+                }
+                Ok(())
+            });
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = (cmd, limit_bytes);
+    }
+}
+
+/// Windows Job Object with a job-wide memory cap. The process placed in it
+/// and every child it starts afterwards share the cap; allocations that would
+/// push committed memory past it fail. Dropping the handle does not kill the
+/// processes.
+#[cfg_attr(not(windows), allow(dead_code))]
+pub struct MemoryJob {
+    #[cfg(windows)]
+    handle: windows_sys::Win32::Foundation::HANDLE,
+}
+
+impl MemoryJob {
+    /// Places `child` in a new Job Object capped at `limit_bytes`. Returns
+    /// Ok(None) on other platforms.
+    pub fn attach(child: &Child, limit_bytes: u64) -> Result<Option<MemoryJob>, String> {
+        #[cfg(windows)]
+        {
+            use std::os::windows::io::AsRawHandle;
+            use windows_sys::Win32::System::JobObjects::{
+                AssignProcessToJobObject, CreateJobObjectW, JobObjectExtendedLimitInformation,
+                SetInformationJobObject, JOBOBJECT_EXTENDED_LIMIT_INFORMATION,
+                JOB_OBJECT_LIMIT_JOB_MEMORY,
+            };
+            // SAFETY: the job handle is owned by the returned MemoryJob and the
+            // info struct lives for the duration of each call.
+            unsafe {
+                let handle = CreateJobObjectW(std::ptr::null(), std::ptr::null());
+                if handle.is_null() {
+                    return Err(format!(
+                        "failed to create job object: {}",
+                        std::io::Error::last_os_error()
+                    ));
+                }
+                let job = MemoryJob { handle };
+                let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = std::mem::zeroed();
+                info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_JOB_MEMORY;
+                info.JobMemoryLimit = usize::try_from(limit_bytes).unwrap_or(usize::MAX);
+                if SetInformationJobObject(
+                    handle,
+                    JobObjectExtendedLimitInformation,
+                    &info as *const _ as *const std::ffi::c_void,
+                    std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+                ) == 0
+                {
+                    return Err(format!(
+                        "failed to set job memory limit: {}",
+                        std::io::Error::last_os_error()
+                    ));
+                }
+                if AssignProcessToJobObject(handle, child.as_raw_handle() as _) == 0 {
+                    return Err(format!(
+                        "failed to assign process to job object: {}",
+                        std::io::Error::last_os_error()
+                    ));
+                }
+                Ok(Some(job))
+            }
+        }
+        #[cfg(not(windows))]
+        {
+            let _ = (child, limit_bytes);
+            Ok(None)
+        }
+    }
+
+    /// Highest committed memory of the whole job so far, in bytes.
+    pub fn peak_bytes(&self) -> Option<u64> {
+        #[cfg(windows)]
+        {
+            use windows_sys::Win32::System::JobObjects::{
+                JobObjectExtendedLimitInformation, QueryInformationJobObject,
+                JOBOBJECT_EXTENDED_LIMIT_INFORMATION,
+            };
+            // SAFETY: `info` is a plain struct sized for the requested class.
+            unsafe {
+                let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = std::mem::zeroed();
+                let ok = QueryInformationJobObject(
+                    self.handle,
+                    JobObjectExtendedLimitInformation,
+                    &mut info as *mut _ as *mut std::ffi::c_void,
+                    std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+                    std::ptr::null_mut(),
+                );
+                (ok != 0).then_some(info.PeakJobMemoryUsed as u64)
+            }
+        }
+        #[cfg(not(windows))]
+        {
+            None
+        }
+    }
+}
+
+#[cfg(windows)]
+impl Drop for MemoryJob {
+    fn drop(&mut self) {
+        // SAFETY: the handle came from CreateJobObjectW and is closed once.
+        unsafe {
+            windows_sys::Win32::Foundation::CloseHandle(self.handle);
+        }
+    }
+}
+
+/// Terminates `pid` and all of its descendants.
+///
+/// Windows uses `taskkill /T /F`. On Linux/macOS the tree is sent SIGTERM
+/// first (both the process group and every descendant found via `pgrep -P`),
+/// then anything still alive after `grace` receives SIGKILL. A zero grace
+/// skips straight to SIGKILL.
+pub fn kill_process_tree(pid: u32, grace: Duration) {
+    if std::env::consts::OS == "windows" {
+        let _ = Command::new("cmd")
+            .args(["/c", &format!("taskkill /PID {pid} /T /F")])
+            .output();
+        return;
+    }
+
+    let mut pids = descendant_pids(pid);
+    pids.push(pid);
+    if !grace.is_zero() {
+        signal_tree(pid, &pids, "TERM");
+        let deadline = Instant::now() + grace;
+        while Instant::now() < deadline {
+            if !pids.iter().any(|p| unix_pid_alive(*p)) {
+                return;
+            }
+            thread::sleep(Duration::from_millis(100));
+        }
+        // Children may have forked while shutting down.
+        for p in descendant_pids(pid) {
+            if !pids.contains(&p) {
+                pids.push(p);
+            }
+        }
+    }
+    signal_tree(pid, &pids, "KILL");
+}
+
+fn signal_tree(root: u32, pids: &[u32], signal: &str) {
+    let flag = format!("-{signal}");
+    // Negative pid targets the process group created by `isolate_process_group`.
+    let _ = Command::new("kill")
+        .args([flag.as_str(), "--", &format!("-{root}")])
+        .output();
+    for p in pids {
+        let _ = Command::new("kill")
+            .args([flag.as_str(), &p.to_string()])
+            .output();
+    }
+}
+
+fn unix_pid_alive(pid: u32) -> bool {
+    Command::new("kill")
+        .args(["-0", &pid.to_string()])
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+/// Whether a process with `pid` currently exists.
+pub fn process_alive(pid: u32) -> bool {
+    if std::env::consts::OS != "windows" {
+        return unix_pid_alive(pid);
+    }
+    Command::new("tasklist")
+        .args(["/FI", &format!("PID eq {pid}"), "/NH", "/FO", "CSV"])
+        .output()
+        .map(|o| String::from_utf8_lossy(&o.stdout).contains(&format!("\"{pid}\"")))
+        .unwrap_or(false)
+}
+
+/// Descendants of `pid`, deepest first, discovered through `pgrep -P`.
+fn descendant_pids(pid: u32) -> Vec<u32> {
+    let mut out = Vec::new();
+    let mut stack = vec![pid];
+    while let Some(parent) = stack.pop() {
+        let Ok(o) = Command::new("pgrep")
+            .args(["-P", &parent.to_string()])
+            .output()
+        else {
+            break;
+        };
+        for child in parse_pid_lines(&String::from_utf8_lossy(&o.stdout)) {
+            if child != pid && !out.contains(&child) {
+                out.push(child);
+                stack.push(child);
+            }
+        }
+    }
+    out.reverse();
+    out
+}
+
+pub fn parse_pid_lines(text: &str) -> Vec<u32> {
+    text.lines()
+        .filter_map(|l| l.trim().parse::<u32>().ok())
+        .collect()
+}
+
+/// Program used to reveal a file or folder in the desktop shell.
+pub fn open_command(os: &str) -> &'static str {
+    match os {
+        "windows" => "explorer",
+        "macos" => "open",
+        _ => "xdg-open",
+    }
+}
+
+/// Opens `path` in the platform file manager (Explorer, Finder, or the
+/// `xdg-open` handler).
+pub fn open_path(path: &Path) -> Result<(), String> {
+    let program = open_command(std::env::consts::OS);
+    Command::new(program)
+        .arg(path)
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("failed to run {program} for {}: {e}", path.display()))
+}
+
+/// Interpreter locations inside a venv, most likely first for `os`.
+pub fn venv_python_candidates(os: &str, venv_dir: &Path) -> Vec<PathBuf> {
+    let windows = venv_dir.join("Scripts").join("python.exe");
+    let unix = [
+        venv_dir.join("bin").join("python"),
+        venv_dir.join("bin").join("python3"),
+    ];
+    if os == "windows" {
+        let mut v = vec![windows];
+        v.extend(unix);
+        v
+    } else {
+        let mut v = unix.to_vec();
+        v.push(windows);
+        v
+    }
+}
+
+/// Fallback interpreter name when no venv is found.
+pub fn system_python(os: &str) -> &'static str {
+    if os == "windows" {
+        "python"
+    } else {
+        "python3"
+    }
+}
@@ -1,7 +1,7 @@
 import { useCallback, useEffect, useMemo, useRef, useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
-import { open as openDialog } from "@tauri-apps/plugin-dialog";
+import { open as openDialog, save as saveDialog } from "@tauri-apps/plugin-dialog";
 
 function escapeHtml(raw) {
   return String(raw ?? "")
@@ -147,6 +147,22 @@ function buildAnalyzePipelineSteps() {
   ];
 }
 
+const RUNS_PAGE_SIZE = 200;
+const ARTIFACT_RANGE_BYTES = 1024 * 1024;
+const LIBRARY_REINDEX_SCAN_ID = "library_reindex";
+
+function listResponseItems(rows) {
+  if (Array.isArray(rows)) return rows;
+  return Array.isArray(rows?.items) ? rows.items : [];
+}
+
+function listResponseWarningText(rows) {
+  const warnings = Array.isArray(rows?.warnings) ? rows.warnings : [];
+  if (warnings.length === 0) return "";
+  const codes = [...new Set(warnings.map((w) => w.code))].join(", ");
+  return `${warnings.length} row(s) skipped (${codes}): ${warnings[0].message}`;
+}
+
 function normalizePathname(pathname) {
   const raw = String(pathname ?? "").trim();
   if (!raw || raw === "/") return "/";
@@ -164,6 +180,9 @@ function pathFromScreen(screen) {
   return "/";
 }
 
+// Graph artifacts above this size are indexed natively and queried in slices.
+const GRAPH_SESSION_THRESHOLD_BYTES = 5 * 1024 * 1024;
+
 const PIPELINE_RUN_ARTIFACT_OPTIONS = [
   { kind: "input", label: "input.json" },
   { kind: "result", label: "result.json" },
@@ -188,15 +207,61 @@ function pipelineRunStatusColor(status) {
   return "#666";
 }
 
+function formatWindowCountdown(seconds) {
+  const total = Math.max(0, Math.floor(Number(seconds) || 0));
+  const h = Math.floor(total / 3600);
+  const m = Math.floor((total % 3600) / 60);
+  return h > 0 ? `${h}h ${m}m` : `${m}m ${total % 60}s`;
+}
+
+function pipelineEtaText(summary) {
+  if (summary?.eta_remaining_sec == null) return "";
+  const remaining = formatWindowCountdown(summary.eta_remaining_sec);
+  if (!summary.eta_at) return `>=${remaining}`;
+  return `${remaining} (~${new Date(Number(summary.eta_at)).toLocaleTimeString()})`;
+}
+
+function errorText(e) {
+  if (e && typeof e === "object" && !(e instanceof Error)) {
+    return Object.entries(e)
+      .map(([key, msg]) => (key === "_" ? String(msg) : `${key}: ${msg}`))
+      .join("; ");
+  }
+  return String(e);
+}
+
+function ordinal(n) {
+  const tail = n % 100;
+  if (tail >= 11 && tail <= 13) return `${n}th`;
+  return `${n}${{ 1: "st", 2: "nd", 3: "rd" }[n % 10] ?? "th"}`;
+}
+
+function queuePositionLabel(eta) {
+  const position = `${ordinal(eta.position)} in queue`;
+  if (eta.finishes_in_ms == null) return position;
+  return `${position}, ~${Math.max(1, Math.round(eta.finishes_in_ms / 60000))} min`;
+}
+
 export default function App() {
   const [paperId, setPaperId] = useState("arxiv:1706.03762");
   const [templates, setTemplates] = useState([]);
   const [templatesLoading, setTemplatesLoading] = useState(false);
   const [templatesError, setTemplatesError] = useState("");
+  const [templateRegistryErrors, setTemplateRegistryErrors] = useState([]);
   const [selectedTemplateId, setSelectedTemplateId] = useState("TEMPLATE_TREE");
   const [templateParams, setTemplateParams] = useState({});
+  const [templateParamCheck, setTemplateParamCheck] = useState(null);
+  const [referenceImport, setReferenceImport] = useState(null);
+  const [referenceImportBusy, setReferenceImportBusy] = useState(false);
+  const [pasteIdsOpen, setPasteIdsOpen] = useState(false);
+  const [pasteIdsText, setPasteIdsText] = useState("");
+  const [pasteIdsValidation, setPasteIdsValidation] = useState(null);
   const [templateParamsRawDraft, setTemplateParamsRawDraft] = useState("{}");
   const [templateParamsRawError, setTemplateParamsRawError] = useState("");
+  const [jobEnvOverridesDraft, setJobEnvOverridesDraft] = useState("");
+  const [jobExtraArgsDraft, setJobExtraArgsDraft] = useState("");
+  const [dryRunResult, setDryRunResult] = useState(null);
+  const [runManifests, setRunManifests] = useState({});
 
   const [running, setRunning] = useState(false);
   const [stdout, setStdout] = useState("");
@@ -211,19 +276,46 @@ export default function App() {
   const [pendingRunSelection, setPendingRunSelection] = useState(false);
 
   const [runtimeCfg, setRuntimeCfg] = useState(null);
+  const [configProfiles, setConfigProfiles] = useState([]);
+  const [configEditorText, setConfigEditorText] = useState("");
+  const [configEditorBase, setConfigEditorBase] = useState(null);
+  const [configEditResult, setConfigEditResult] = useState(null);
+  const [configApplyReport, setConfigApplyReport] = useState(null);
+  const [startupIssues, setStartupIssues] = useState([]);
+  const [quarantinedFiles, setQuarantinedFiles] = useState([]);
+  const [runNoteDraft, setRunNoteDraft] = useState("");
+  const [runNoteStatus, setRunNoteStatus] = useState("");
+  const [vizPatternDraft, setVizPatternDraft] = useState("");
+  const [benchmarkBusy, setBenchmarkBusy] = useState(false);
+  const [devCommandsEnabled, setDevCommandsEnabled] = useState(import.meta.env.DEV);
+  const [runGroupBy, setRunGroupBy] = useState("");
+  const [runCommitFilter, setRunCommitFilter] = useState("");
+  const [runGroups, setRunGroups] = useState([]);
+  const [benchmarkReport, setBenchmarkReport] = useState(null);
+  const [syntheticReport, setSyntheticReport] = useState(null);
   const [cfgLoading, setCfgLoading] = useState(false);
   const [cfgError, setCfgError] = useState("");
   const [pipelineRootDraft, setPipelineRootDraft] = useState("");
   const [outDirDraft, setOutDirDraft] = useState("");
+  const [relocateResult, setRelocateResult] = useState(null);
   const [normalized, setNormalized] = useState(null);
+  const [identifierResolution, setIdentifierResolution] = useState(null);
+  const [identifierResolving, setIdentifierResolving] = useState(false);
   const [normalizeLoading, setNormalizeLoading] = useState(false);
   const [preflight, setPreflight] = useState(null);
+  const [s2KeyCheck, setS2KeyCheck] = useState(null);
+  const [rateLimitStatus, setRateLimitStatus] = useState(null);
   const [preflightLoading, setPreflightLoading] = useState(false);
   const [preflightError, setPreflightError] = useState("");
   const [runs, setRuns] = useState([]);
+  const [runsPage, setRunsPage] = useState(null);
   const [runsLoading, setRunsLoading] = useState(false);
   const [runsError, setRunsError] = useState("");
   const [selectedRunId, setSelectedRunId] = useState("");
+  const [refreshHints, setRefreshHints] = useState(null);
+  const [libraryUpdatedAt, setLibraryUpdatedAt] = useState(null);
+  const [runsPollTick, setRunsPollTick] = useState(0);
+  const seenLibraryUpdatedAtRef = useRef(null);
   const [selectedArtifact, setSelectedArtifact] = useState("tree_md");
   const [artifactLoading, setArtifactLoading] = useState(false);
   const [artifactError, setArtifactError] = useState("");
@@ -231,23 +323,39 @@ export default function App() {
   const [artifactWarnings, setArtifactWarnings] = useState([]);
   const [artifactWrap, setArtifactWrap] = useState(true);
   const [graphParsed, setGraphParsed] = useState(null);
+  const [graphMergeInfo, setGraphMergeInfo] = useState(null);
+  const [graphAnalysis, setGraphAnalysis] = useState(null);
   const [graphParseLoading, setGraphParseLoading] = useState(false);
   const [graphParseError, setGraphParseError] = useState("");
+  const [graphSession, setGraphSession] = useState(null);
+  const [annotationDraft, setAnnotationDraft] = useState({ type: "lines", start: "1", end: "", nodeId: "", text: "" });
+  const [annotationStatus, setAnnotationStatus] = useState("");
   const [graphQuery, setGraphQuery] = useState("");
   const [graphTypeFilter, setGraphTypeFilter] = useState("all");
   const [graphYearFrom, setGraphYearFrom] = useState("");
   const [graphYearTo, setGraphYearTo] = useState("");
   const [graphHasEdgesOnly, setGraphHasEdgesOnly] = useState(false);
+  const [graphDotStatus, setGraphDotStatus] = useState("");
   const [selectedGraphNodeId, setSelectedGraphNodeId] = useState("");
   const [runArtifactCatalog, setRunArtifactCatalog] = useState([]);
   const [runArtifactCatalogLoading, setRunArtifactCatalogLoading] = useState(false);
   const [runArtifactCatalogError, setRunArtifactCatalogError] = useState("");
+  const [artifactSearchQuery, setArtifactSearchQuery] = useState("");
+  const [artifactSearchResult, setArtifactSearchResult] = useState(null);
   const [artifactCatalogByRun, setArtifactCatalogByRun] = useState({});
   const [jobs, setJobs] = useState([]);
+  const [jobWindowWaits, setJobWindowWaits] = useState({});
+  const [jobQueueEtas, setJobQueueEtas] = useState({});
+  const [failureClusters, setFailureClusters] = useState(null);
   const [jobsLoading, setJobsLoading] = useState(false);
   const [jobsError, setJobsError] = useState("");
   const [selectedJobId, setSelectedJobId] = useState("");
+  const [jobLiveOutput, setJobLiveOutput] = useState({});
+  const [shutdownStatus, setShutdownStatus] = useState(null);
   const [pipelines, setPipelines] = useState([]);
+  const [pipelineTemplates, setPipelineTemplates] = useState([]);
+  const [pipelineTemplateWarnings, setPipelineTemplateWarnings] = useState([]);
+  const [selectedPipelineTemplateId, setSelectedPipelineTemplateId] = useState("full_analysis");
   const [pipelinesLoading, setPipelinesLoading] = useState(false);
   const [pipelinesError, setPipelinesError] = useState("");
   const [pipelineValidationMissing, setPipelineValidationMissing] = useState([]);
@@ -274,11 +382,21 @@ export default function App() {
   const [desktopSettings, setDesktopSettings] = useState(null);
   const [settingsLoading, setSettingsLoading] = useState(false);
   const [settingsError, setSettingsError] = useState("");
+  const [settingsResetSection, setSettingsResetSection] = useState("auto_retry");
   const [tickResult, setTickResult] = useState(null);
+  const [notificationFeed, setNotificationFeed] = useState({ items: [], unread_count: 0 });
+  const [notificationsOpen, setNotificationsOpen] = useState(false);
+  const [webhookDraft, setWebhookDraft] = useState({ url: "", secret: "" });
   const [diagnosticsRows, setDiagnosticsRows] = useState([]);
   const [diagnosticsLoading, setDiagnosticsLoading] = useState(false);
   const [diagnosticsError, setDiagnosticsError] = useState("");
+  const [runtimeCacheStats, setRuntimeCacheStats] = useState(null);
+  const [runMetaCacheStats, setRunMetaCacheStats] = useState(null);
   const [collectingDiagnostics, setCollectingDiagnostics] = useState(false);
+  const [diagRedaction, setDiagRedaction] = useState("standard");
+  const [diagPreview, setDiagPreview] = useState(null);
+  const [auditEvents, setAuditEvents] = useState(null);
+  const [auditQuery, setAuditQuery] = useState("");
   const [diagnosticsOneClickBusy, setDiagnosticsOneClickBusy] = useState(false);
   const [diagnosticsOneClickMessage, setDiagnosticsOneClickMessage] = useState("");
   const [latestDiagnosticId, setLatestDiagnosticId] = useState("");
@@ -297,6 +415,8 @@ export default function App() {
   const [workspaceImportZipPath, setWorkspaceImportZipPath] = useState("");
   const [workspaceImportMode, setWorkspaceImportMode] = useState("keep_current");
   const [workspaceImportDryRun, setWorkspaceImportDryRun] = useState(false);
+  const [stateBundlePath, setStateBundlePath] = useState("");
+  const [stateBundleMessage, setStateBundleMessage] = useState("");
   const [snapshotLoading, setSnapshotLoading] = useState(false);
   const [workspaceFixingRuntime, setWorkspaceFixingRuntime] = useState(false);
   const [workspaceFixRuntimeMessage, setWorkspaceFixRuntimeMessage] = useState("");
@@ -316,11 +436,36 @@ export default function App() {
     status: "",
     kind: "",
     tag: "",
+    collection: "",
   });
+  const [collections, setCollections] = useState([]);
+  const [newCollectionName, setNewCollectionName] = useState("");
   const [tagInput, setTagInput] = useState("");
+  const [metaTitleInput, setMetaTitleInput] = useState("");
+  const [metaYearInput, setMetaYearInput] = useState("");
+  const [metaCanonicalInput, setMetaCanonicalInput] = useState("");
+  const [mergeKeyInput, setMergeKeyInput] = useState("");
   const [libraryReindexInfo, setLibraryReindexInfo] = useState(null);
+  const [outDirWatchStatus, setOutDirWatchStatus] = useState(null);
+  const [storageReport, setStorageReport] = useState(null);
+  const [trashItems, setTrashItems] = useState(null);
+  const [schemaBackups, setSchemaBackups] = useState(null);
+  const [libraryExportFormat, setLibraryExportFormat] = useState("csv");
+  const [libraryExportInfo, setLibraryExportInfo] = useState(null);
+  const [libraryMergeStrategy, setLibraryMergeStrategy] = useState("union");
+  const [enrichStatus, setEnrichStatus] = useState(null);
+  const [enrichFromS2, setEnrichFromS2] = useState(false);
+  const [externalRunFilter, setExternalRunFilter] = useState({ status: null });
+  const [externalImportResult, setExternalImportResult] = useState(null);
   const [librarySearchQuery, setLibrarySearchQuery] = useState("");
+  const [artifactSearchQuery, setArtifactSearchQuery] = useState("");
+  const [artifactSearchHits, setArtifactSearchHits] = useState(null);
+  const [artifactSearchLoading, setArtifactSearchLoading] = useState(false);
   const [librarySearchRows, setLibrarySearchRows] = useState([]);
+  const [savedSearches, setSavedSearches] = useState([]);
+  const [activeSavedSearch, setActiveSavedSearch] = useState("");
+  const [savedSearchRows, setSavedSearchRows] = useState([]);
+  const [saveSearchName, setSaveSearchName] = useState("");
   const [librarySearchLoading, setLibrarySearchLoading] = useState(false);
   const [pipelineRuns, setPipelineRuns] = useState([]);
   const [pipelineRunsLoading, setPipelineRunsLoading] = useState(false);
@@ -328,6 +473,7 @@ export default function App() {
   const [runDashboardStats, setRunDashboardStats] = useState(null);
   const [runDashboardStatsLoading, setRunDashboardStatsLoading] = useState(false);
   const [runDashboardStatsError, setRunDashboardStatsError] = useState("");
+  const [jobDashboardStats, setJobDashboardStats] = useState(null);
   const [selectedPipelineRunId, setSelectedPipelineRunId] = useState("");
   const [pipelineRunTab, setPipelineRunTab] = useState("input");
   const [pipelineRunQuery, setPipelineRunQuery] = useState("");
@@ -372,13 +518,17 @@ export default function App() {
     setTemplatesLoading(true);
     setTemplatesError("");
     try {
-      const res = await invoke("list_task_templates");
+      const res = await invoke("list_task_templates", { locale: navigator.language || "en" });
       const list = Array.isArray(res) ? res : [];
       setTemplates(list);
       setSelectedTemplateId((prev) => {
         if (prev && list.some((t) => t.id === prev)) return prev;
         return list[0]?.id ?? "";
       });
+      const sources = await invoke("get_template_registry_status").catch(() => []);
+      setTemplateRegistryErrors(
+        (Array.isArray(sources) ? sources : []).flatMap((src) => src.errors.map((err) => `${src.path}: ${err}`)),
+      );
     } catch (e) {
       setTemplates([]);
       setTemplatesError(String(e));
@@ -387,6 +537,32 @@ export default function App() {
     }
   }
 
+  async function loadStartupIssues() {
+    try {
+      const issues = await invoke("get_startup_issues");
+      setStartupIssues(Array.isArray(issues) ? issues : []);
+    } catch {
+      setStartupIssues([]);
+    }
+  }
+
+  async function loadDevCommandsEnabled() {
+    try {
+      setDevCommandsEnabled(Boolean(await invoke("dev_commands_enabled")));
+    } catch {
+      setDevCommandsEnabled(false);
+    }
+  }
+
+  async function loadQuarantinedFiles() {
+    try {
+      const files = await invoke("list_quarantined_files");
+      setQuarantinedFiles(Array.isArray(files) ? files : []);
+    } catch {
+      setQuarantinedFiles([]);
+    }
+  }
+
   async function loadPreflight() {
     setPreflightLoading(true);
     setPreflightError("");
@@ -401,16 +577,30 @@ export default function App() {
     }
   }
 
-  async function loadRuns() {
+  async function onCheckS2Key() {
+    setS2KeyCheck({ status: "checking", message: "" });
+    try {
+      setS2KeyCheck(await invoke("check_s2_key"));
+    } catch (e) {
+      setS2KeyCheck({ ok: false, status: "error", message: String(e) });
+    }
+  }
+
+  async function loadRuns(groupBy = runGroupBy, pipelineCommit = runCommitFilter) {
     setRunsLoading(true);
     setRunsError("");
     try {
+      const commit = String(pipelineCommit ?? "").trim();
       const rows = await invoke("list_runs", {
-        limit: 500,
-        filters: null,
+        limit: null,
+        filters: groupBy || commit ? { group_by: groupBy || null, pipeline_commit: commit || null } : null,
+        page: { page_size: RUNS_PAGE_SIZE },
       });
-      const list = Array.isArray(rows) ? rows : [];
+      const list = listResponseItems(rows);
       setRuns(list);
+      setRunsPage(rows?.page ?? null);
+      setRunGroups(Array.isArray(rows?.groups) ? rows.groups : []);
+      setRunsError(listResponseWarningText(rows));
       setSelectedRunId((prev) => {
         if (prev && list.some((r) => r.run_id === prev)) return prev;
         return list[0]?.run_id ?? "";
@@ -423,13 +613,34 @@ export default function App() {
     }
   }
 
+  async function loadMoreRuns() {
+    if (runsLoading || !runsPage?.next_cursor) return;
+    setRunsLoading(true);
+    try {
+      const commit = String(runCommitFilter ?? "").trim();
+      const rows = await invoke("list_runs", {
+        limit: null,
+        filters: commit ? { pipeline_commit: commit } : null,
+        page: { cursor: runsPage.next_cursor, page_size: RUNS_PAGE_SIZE },
+      });
+      const list = listResponseItems(rows);
+      setRuns((prev) => [...prev, ...list.filter((r) => !prev.some((p) => p.run_id === r.run_id))]);
+      setRunsPage(rows?.page ?? null);
+    } catch (e) {
+      setRunsError(String(e));
+    } finally {
+      setRunsLoading(false);
+    }
+  }
+
   async function loadPipelineRuns() {
     setPipelineRunsLoading(true);
     setPipelineRunsError("");
     try {
       const rows = await invoke("list_pipeline_runs", { limit: 200 });
-      const list = Array.isArray(rows) ? rows : [];
+      const list = listResponseItems(rows);
       setPipelineRuns(list);
+      setPipelineRunsError(listResponseWarningText(rows));
       setSelectedPipelineRunId((prev) => {
         if (prev && list.some((r) => r.run_id === prev)) return prev;
         return list[0]?.run_id ?? "";
@@ -447,8 +658,12 @@ export default function App() {
     setRunDashboardStatsLoading(true);
     setRunDashboardStatsError("");
     try {
-      const stats = await invoke("get_run_dashboard_stats", { limit: 500 });
+      const [stats, jobStats] = await Promise.all([
+        invoke("get_run_dashboard_stats", { limit: 500 }),
+        invoke("get_dashboard_stats", { days: 30 }),
+      ]);
       setRunDashboardStats(stats ?? null);
+      setJobDashboardStats(jobStats ?? null);
     } catch (e) {
       setRunDashboardStats(null);
       setRunDashboardStatsError(String(e));
@@ -563,9 +778,24 @@ export default function App() {
     setJobsLoading(true);
     setJobsError("");
     try {
-      const rows = await invoke("list_jobs");
-      const list = Array.isArray(rows) ? rows : [];
+      const rows = await invoke("list_jobs", { page: null });
+      const list = listResponseItems(rows);
       setJobs(list);
+      try {
+        const waits = await invoke("list_job_window_waits");
+        const loadedAt = Date.now();
+        setJobWindowWaits(
+          Object.fromEntries((Array.isArray(waits) ? waits : []).map((w) => [w.job_id, { ...w, loadedAt }]))
+        );
+      } catch {
+        setJobWindowWaits({});
+      }
+      try {
+        const queue = await invoke("get_queue_status");
+        setJobQueueEtas(Object.fromEntries((queue?.queued ?? []).map((q) => [q.job_id, q])));
+      } catch {
+        setJobQueueEtas({});
+      }
       setSelectedJobId((prev) => {
         if (prev && list.some((j) => j.job_id === prev)) return prev;
         return list[0]?.job_id ?? "";
@@ -578,6 +808,17 @@ export default function App() {
     }
   }
 
+  async function loadFailureClusters(days = 30) {
+    setJobsError("");
+    try {
+      const since_ms = days ? Date.now() - days * 24 * 60 * 60 * 1000 : null;
+      const report = await invoke("cluster_failures", { range: { since_ms } });
+      setFailureClusters(report ?? null);
+    } catch (e) {
+      setJobsError(String(e));
+    }
+  }
+
   async function loadPipelines() {
     setPipelinesLoading(true);
     setPipelinesError("");
@@ -603,14 +844,26 @@ export default function App() {
     try {
       const settings = await invoke("get_settings");
       setDesktopSettings(settings ?? null);
+      setOutDirWatchStatus(await invoke("get_out_dir_watch_status"));
     } catch (e) {
       setDesktopSettings(null);
-      setSettingsError(String(e));
+      setSettingsError(errorText(e));
     } finally {
       setSettingsLoading(false);
     }
   }
 
+  async function onToggleQueuePaused() {
+    const paused = !!desktopSettings?.queue?.paused;
+    try {
+      const status = await invoke(paused ? "resume_queue" : "pause_queue");
+      setDesktopSettings((prev) => (prev ? { ...prev, queue: { ...prev.queue, paused: !!status?.paused } } : prev));
+      await loadJobs();
+    } catch (e) {
+      alert(String(e));
+    }
+  }
+
   async function updateAutoRetryEnabled(enabled) {
     if (!desktopSettings) return;
     setSettingsError("");
@@ -623,183 +876,546 @@ export default function App() {
       });
       setDesktopSettings(updated ?? null);
     } catch (e) {
-      setSettingsError(String(e));
+      setSettingsError(errorText(e));
     }
   }
 
-  async function loadPipelineRepoStatus() {
+  async function updateAutoRetryScheduler(enabled) {
+    if (!desktopSettings) return;
+    setSettingsError("");
     try {
-      const status = await invoke("get_pipeline_repo_status");
-      setPipelineRepoStatus(status ?? null);
-      setPipelineRepoRemoteUrl(String(status?.remote_url ?? ""));
-      setPipelineRepoLocalPath(String(status?.local_path ?? ""));
-      setPipelineRepoRef(String(status?.git_ref ?? "main"));
+      const updated = await invoke("update_settings", {
+        settings: {
+          ...desktopSettings,
+          auto_retry_scheduler_enabled: !!enabled,
+        },
+      });
+      setDesktopSettings(updated ?? null);
     } catch (e) {
-      setPipelineRepoStatus(null);
-      setPipelineRepoError(String(e));
+      setSettingsError(errorText(e));
     }
   }
 
-  async function onSavePipelineRepoSettings() {
-    setPipelineRepoBusy(true);
-    setPipelineRepoError("");
+  async function updateOfflineMode(enabled) {
+    if (!desktopSettings) return;
+    setSettingsError("");
     try {
-      const settings = await invoke("update_pipeline_repo_settings", {
-        update: {
-          remote_url: pipelineRepoRemoteUrl,
-          local_path: pipelineRepoLocalPath,
-          git_ref: pipelineRepoRef,
+      const updated = await invoke("update_settings", {
+        settings: {
+          ...desktopSettings,
+          offline_mode: !!enabled,
         },
       });
-      const repo = settings?.pipeline_repo ?? {};
-      setPipelineRepoRemoteUrl(String(repo.remote_url ?? pipelineRepoRemoteUrl));
-      setPipelineRepoLocalPath(String(repo.local_path ?? pipelineRepoLocalPath));
-      setPipelineRepoRef(String(repo.git_ref ?? pipelineRepoRef));
-      await loadPipelineRepoStatus();
-      await Promise.all([loadRuntimeConfig(true), loadPreflight(), loadSettings()]);
+      setDesktopSettings(updated ?? null);
+      await loadTemplates();
     } catch (e) {
-      setPipelineRepoError(String(e));
-    } finally {
-      setPipelineRepoBusy(false);
+      setSettingsError(errorText(e));
     }
   }
 
-  async function onBootstrapPipelineRepo() {
-    setPipelineRepoBusy(true);
-    setPipelineRepoError("");
+  async function updateRunLayout(layout) {
+    if (!desktopSettings) return;
+    setSettingsError("");
     try {
-      const status = await invoke("bootstrap_pipeline_repo");
-      setPipelineRepoStatus(status ?? null);
-      await Promise.all([loadRuntimeConfig(true), loadPreflight(), loadSettings(), loadPipelineRepoStatus()]);
+      const updated = await invoke("update_settings", {
+        settings: {
+          ...desktopSettings,
+          run_layout: layout,
+        },
+      });
+      setDesktopSettings(updated ?? null);
     } catch (e) {
-      setPipelineRepoError(String(e));
-      await loadPipelineRepoStatus();
-    } finally {
-      setPipelineRepoBusy(false);
+      setSettingsError(errorText(e));
     }
   }
 
-  function onClearBootstrapLogs() {
-    setBootstrapLogLines([]);
-  }
-
-  async function onBootstrapPipelineRepoWithLogs() {
-    setBootstrapLogBusy(true);
-    setPipelineRepoBusy(true);
-    setPipelineRepoError("");
-    setBootstrapLogLines([]);
-
-    let unlistenLog = null;
-    let unlistenDone = null;
+  async function reorganizeRuns() {
+    setSettingsError("");
     try {
-      unlistenLog = await listen("bootstrap_pipeline_repo:log", (event) => {
-        const line = String(event?.payload ?? "");
-        if (!line) return;
-        setBootstrapLogLines((prev) => [...prev, line]);
-      });
-
-      unlistenDone = await listen("bootstrap_pipeline_repo:done", (event) => {
-        const payload = event?.payload ?? {};
-        const ok = payload?.ok === true;
-        const message = String(payload?.message ?? "").trim();
-        const suffix = message ? `: ${message}` : "";
-        setBootstrapLogLines((prev) => [...prev, `[done] ${ok ? "ok" : "error"}${suffix}`]);
-      });
-
-      const status = await invoke("bootstrap_pipeline_repo_stream");
-      setPipelineRepoStatus(status ?? null);
-      await Promise.all([loadRuntimeConfig(true), loadPreflight(), loadSettings(), loadPipelineRepoStatus()]);
-    } catch (e) {
-      const msg = String(e);
-      setPipelineRepoError(msg);
-      setBootstrapLogLines((prev) => [...prev, `[error] ${msg}`]);
-      await loadPipelineRepoStatus();
-    } finally {
-      if (typeof unlistenLog === "function") {
-        unlistenLog();
-      }
-      if (typeof unlistenDone === "function") {
-        unlistenDone();
+      const plan = await invoke("reorganize_runs", { dryRun: true });
+      if (plan.moved.length === 0) {
+        alert("All runs already match the folder layout.");
+        return;
       }
-      setBootstrapLogBusy(false);
-      setPipelineRepoBusy(false);
+      if (!confirm(`Move ${plan.moved.length} run folder(s) to the ${plan.layout} layout?`)) return;
+      const done = await invoke("reorganize_runs", { dryRun: false });
+      const problems = done.warnings.map((w) => `${w.context ?? ""}: ${w.message}`).join("\n");
+      alert(`Moved ${done.moved.length} run(s).${problems ? `\n\n${problems}` : ""}`);
+      await loadRuns();
+    } catch (e) {
+      setSettingsError(errorText(e));
     }
   }
 
-  async function onUpdatePipelineRepo() {
-    setPipelineRepoBusy(true);
-    setPipelineRepoError("");
+  async function loadRateLimitStatus() {
     try {
-      const status = await invoke("update_pipeline_repo");
-      setPipelineRepoStatus(status ?? null);
-      await Promise.all([loadRuntimeConfig(true), loadPreflight(), loadSettings(), loadPipelineRepoStatus()]);
+      const status = await invoke("get_rate_limit_status");
+      setRateLimitStatus(status ?? null);
     } catch (e) {
-      setPipelineRepoError(String(e));
-      await loadPipelineRepoStatus();
-    } finally {
-      setPipelineRepoBusy(false);
+      setSettingsError(errorText(e));
     }
   }
 
-  async function onValidatePipelineRepo() {
-    setPipelineRepoBusy(true);
-    setPipelineRepoError("");
+  async function updateAutoRunNotes(enabled) {
+    if (!desktopSettings) return;
+    setSettingsError("");
     try {
-      const result = await invoke("validate_pipeline_repo");
-      setPipelineRepoValidate(result ?? null);
+      const updated = await invoke("update_settings", {
+        settings: {
+          ...desktopSettings,
+          auto_run_notes: !!enabled,
+        },
+      });
+      setDesktopSettings(updated ?? null);
     } catch (e) {
-      setPipelineRepoValidate(null);
-      setPipelineRepoError(String(e));
-    } finally {
-      setPipelineRepoBusy(false);
+      setSettingsError(errorText(e));
     }
   }
 
-  async function onOpenPipelineRepoFolder() {
+  async function onResetSettingsSection() {
+    if (!window.confirm(`Reset the ${settingsResetSection} settings to their defaults?`)) return;
+    setSettingsError("");
     try {
-      await invoke("open_pipeline_repo_folder");
+      const updated = await invoke("reset_settings", { section: settingsResetSection });
+      setDesktopSettings(updated ?? null);
+      if (settingsResetSection === "watcher") {
+        setOutDirWatchStatus(await invoke("get_out_dir_watch_status"));
+      }
     } catch (e) {
-      setPipelineRepoError(String(e));
+      setSettingsError(errorText(e));
     }
   }
 
-  async function onOpenAuditLog() {
+  async function updateMaxConcurrentJobs(value) {
+    if (!desktopSettings) return;
+    const n = Number(value);
+    if (!Number.isInteger(n) || n < 1) return;
+    setSettingsError("");
     try {
-      await invoke("open_audit_log");
+      const updated = await invoke("update_settings", {
+        settings: {
+          ...desktopSettings,
+          queue: { ...desktopSettings.queue, max_concurrent_jobs: n },
+        },
+      });
+      setDesktopSettings(updated ?? null);
     } catch (e) {
-      alert(String(e));
+      setSettingsError(errorText(e));
     }
   }
 
-  async function tickAutoRetry() {
-    if (autoRetryTickBusyRef.current) return;
-    autoRetryTickBusyRef.current = true;
+  async function updateWatchOutDir(enabled) {
+    if (!desktopSettings) return;
+    setSettingsError("");
     try {
-      const res = await invoke("tick_auto_retry");
-      setTickResult(res ?? null);
-      if (res?.acted) {
-        await Promise.all([loadPipelines(), loadJobs(), loadRuns()]);
-      }
+      const updated = await invoke("update_settings", {
+        settings: {
+          ...desktopSettings,
+          watcher: { ...desktopSettings.watcher, enabled: !!enabled },
+        },
+      });
+      setDesktopSettings(updated ?? null);
+      setOutDirWatchStatus(await invoke("get_out_dir_watch_status"));
     } catch (e) {
-      setTickResult({ acted: false, reason: String(e) });
-    } finally {
-      autoRetryTickBusyRef.current = false;
+      setSettingsError(errorText(e));
     }
   }
 
-  async function loadDiagnostics() {
-    setDiagnosticsLoading(true);
-    setDiagnosticsError("");
+  async function updateRetention(patch) {
+    if (!desktopSettings) return;
+    setSettingsError("");
     try {
-      const rows = await invoke("list_diagnostics");
-      const list = Array.isArray(rows) ? rows : [];
-      setDiagnosticsRows(list);
-      setSelectedDiagId((prev) => {
-        if (prev && list.some((r) => r.diag_id === prev)) return prev;
-        return list[0]?.diag_id ?? "";
+      const updated = await invoke("update_settings", {
+        settings: {
+          ...desktopSettings,
+          retention: { ...(desktopSettings.retention ?? {}), ...patch },
+        },
       });
+      setDesktopSettings(updated ?? null);
+      if (storageReport) setStorageReport(await invoke("storage_report"));
     } catch (e) {
-      setDiagnosticsRows([]);
+      setSettingsError(errorText(e));
+    }
+  }
+
+  async function updateNotificationPrefs(patch) {
+    if (!desktopSettings) return;
+    setSettingsError("");
+    try {
+      const updated = await invoke("update_settings", {
+        settings: {
+          ...desktopSettings,
+          notifications: { ...(desktopSettings.notifications ?? {}), ...patch },
+        },
+      });
+      setDesktopSettings(updated ?? null);
+    } catch (e) {
+      setSettingsError(errorText(e));
+    }
+  }
+
+  async function saveWebhook() {
+    if (!desktopSettings) return;
+    setSettingsError("");
+    const url = webhookDraft.url.trim();
+    const secret = webhookDraft.secret.trim();
+    try {
+      const updated = await invoke("update_settings", {
+        settings: {
+          ...desktopSettings,
+          webhook: url ? { url, ...(secret ? { secret } : {}) } : null,
+        },
+      });
+      setDesktopSettings(updated ?? null);
+    } catch (e) {
+      setSettingsError(errorText(e));
+    }
+  }
+
+  async function loadNotifications() {
+    try {
+      const res = await invoke("list_notifications", { unreadOnly: false, limit: 50 });
+      setNotificationFeed(res ?? { items: [], unread_count: 0 });
+    } catch {
+      // The feed is optional; a broken notifications.json shows up in startup issues.
+    }
+  }
+
+  async function onMarkNotificationRead(notificationId) {
+    try {
+      await invoke("mark_notification_read", { notificationId, read: true });
+      await loadNotifications();
+    } catch (e) {
+      alert(String(e));
+    }
+  }
+
+  async function onLoadStorageReport() {
+    setSettingsError("");
+    try {
+      setStorageReport(await invoke("storage_report"));
+    } catch (e) {
+      setSettingsError(errorText(e));
+    }
+  }
+
+  async function onLoadTrash() {
+    setSettingsError("");
+    try {
+      const items = await invoke("list_trash");
+      setTrashItems(Array.isArray(items) ? items : []);
+    } catch (e) {
+      setSettingsError(errorText(e));
+    }
+  }
+
+  async function onRestoreFromTrash(id) {
+    setSettingsError("");
+    try {
+      const res = await invoke("restore_from_trash", { id });
+      if (res.skipped.length > 0) {
+        alert(`Already present, not restored: ${res.skipped.join(", ")}`);
+      }
+      await Promise.all([onLoadTrash(), loadRuns(), loadJobs(), loadPipelines(), loadLibraryRows()]);
+    } catch (e) {
+      setSettingsError(errorText(e));
+    }
+  }
+
+  async function onLoadSchemaBackups() {
+    setSettingsError("");
+    try {
+      const items = await invoke("list_schema_backups");
+      setSchemaBackups(Array.isArray(items) ? items : []);
+    } catch (e) {
+      setSettingsError(errorText(e));
+    }
+  }
+
+  async function onRollbackSchema(backup) {
+    if (
+      !window.confirm(
+        `Replace ${backup.file} with the schema v${backup.schema_version} copy from ${new Date(backup.created_at_ms).toLocaleString()}?`,
+      )
+    )
+      return;
+    setSettingsError("");
+    try {
+      await invoke("rollback_schema", { subsystem: backup.subsystem, backupId: backup.backup_id });
+      alert(`${backup.file} restored. Quit now if you are going back to an older version.`);
+      await onLoadSchemaBackups();
+    } catch (e) {
+      setSettingsError(errorText(e));
+    }
+  }
+
+  async function onSweepRetentionNow() {
+    if (!window.confirm(`Apply the retention policy now (${storageReport?.retention_candidates?.length ?? 0} runs)?`)) return;
+    try {
+      const res = await invoke("sweep_retention_now");
+      if (res.errors.length > 0) setSettingsError(res.errors.join("; "));
+      setStorageReport(await invoke("storage_report"));
+      await Promise.all([loadRuns(), loadJobs(), loadPipelines(), loadLibraryRows()]);
+    } catch (e) {
+      setSettingsError(errorText(e));
+    }
+  }
+
+  async function updatePrivacyDisplayMode(enabled) {
+    if (!desktopSettings) return;
+    setSettingsError("");
+    try {
+      const updated = await invoke("update_settings", {
+        settings: {
+          ...desktopSettings,
+          ui: { ...desktopSettings.ui, privacy_display_mode: !!enabled },
+        },
+      });
+      setDesktopSettings(updated ?? null);
+      await Promise.all([loadRuns(), loadJobs(), loadPipelines(), loadPipelineRuns(), loadLibraryRows()]);
+    } catch (e) {
+      setSettingsError(errorText(e));
+    }
+  }
+
+  async function updatePrimaryVizPolicy(mode, pattern) {
+    if (!desktopSettings) return;
+    setSettingsError("");
+    const policy = mode === "prefer_named_pattern"
+      ? { mode, pattern: String(pattern ?? "").trim() }
+      : { mode };
+    try {
+      const updated = await invoke("update_settings", {
+        settings: {
+          ...desktopSettings,
+          primary_viz_policy: policy,
+        },
+      });
+      setDesktopSettings(updated ?? null);
+    } catch (e) {
+      setSettingsError(errorText(e));
+    }
+  }
+
+  async function loadRunNote(runId) {
+    setRunNoteStatus("");
+    if (!runId) {
+      setRunNoteDraft("");
+      return;
+    }
+    try {
+      const note = await invoke("get_run_note", { runId });
+      setRunNoteDraft(String(note?.content ?? ""));
+    } catch (e) {
+      setRunNoteDraft("");
+      setRunNoteStatus(String(e));
+    }
+  }
+
+  async function onSaveRunNote() {
+    if (!selectedRunId) return;
+    try {
+      const note = await invoke("save_run_note", { runId: selectedRunId, content: runNoteDraft });
+      setRunNoteStatus(`saved: ${note?.path ?? ""}`);
+    } catch (e) {
+      setRunNoteStatus(String(e));
+    }
+  }
+
+  async function updateSearchTransliteration(enabled) {
+    if (!desktopSettings) return;
+    setSettingsError("");
+    try {
+      const updated = await invoke("update_settings", {
+        settings: {
+          ...desktopSettings,
+          ui: { ...desktopSettings.ui, search_transliteration: !!enabled },
+        },
+      });
+      setDesktopSettings(updated ?? null);
+      if (String(librarySearchQuery).trim()) {
+        await loadLibrarySearch(librarySearchQuery, libraryFilters);
+      }
+    } catch (e) {
+      setSettingsError(errorText(e));
+    }
+  }
+
+  async function loadPipelineRepoStatus() {
+    try {
+      const status = await invoke("get_pipeline_repo_status");
+      setPipelineRepoStatus(status ?? null);
+      setPipelineRepoRemoteUrl(String(status?.remote_url ?? ""));
+      setPipelineRepoLocalPath(String(status?.local_path ?? ""));
+      setPipelineRepoRef(String(status?.git_ref ?? "main"));
+    } catch (e) {
+      setPipelineRepoStatus(null);
+      setPipelineRepoError(String(e));
+    }
+  }
+
+  async function onSavePipelineRepoSettings() {
+    setPipelineRepoBusy(true);
+    setPipelineRepoError("");
+    try {
+      const settings = await invoke("update_pipeline_repo_settings", {
+        update: {
+          remote_url: pipelineRepoRemoteUrl,
+          local_path: pipelineRepoLocalPath,
+          git_ref: pipelineRepoRef,
+        },
+      });
+      const repo = settings?.pipeline_repo ?? {};
+      setPipelineRepoRemoteUrl(String(repo.remote_url ?? pipelineRepoRemoteUrl));
+      setPipelineRepoLocalPath(String(repo.local_path ?? pipelineRepoLocalPath));
+      setPipelineRepoRef(String(repo.git_ref ?? pipelineRepoRef));
+      await loadPipelineRepoStatus();
+      await Promise.all([loadRuntimeConfig(true), loadPreflight(), loadSettings()]);
+    } catch (e) {
+      setPipelineRepoError(String(e));
+    } finally {
+      setPipelineRepoBusy(false);
+    }
+  }
+
+  async function onBootstrapPipelineRepo() {
+    setPipelineRepoBusy(true);
+    setPipelineRepoError("");
+    try {
+      const status = await invoke("bootstrap_pipeline_repo");
+      setPipelineRepoStatus(status ?? null);
+      await Promise.all([loadRuntimeConfig(true), loadPreflight(), loadSettings(), loadPipelineRepoStatus()]);
+    } catch (e) {
+      setPipelineRepoError(String(e));
+      await loadPipelineRepoStatus();
+    } finally {
+      setPipelineRepoBusy(false);
+    }
+  }
+
+  function onClearBootstrapLogs() {
+    setBootstrapLogLines([]);
+  }
+
+  async function onBootstrapPipelineRepoWithLogs() {
+    setBootstrapLogBusy(true);
+    setPipelineRepoBusy(true);
+    setPipelineRepoError("");
+    setBootstrapLogLines([]);
+
+    let unlistenLog = null;
+    let unlistenDone = null;
+    try {
+      unlistenLog = await listen("bootstrap_pipeline_repo:log", (event) => {
+        const line = String(event?.payload ?? "");
+        if (!line) return;
+        setBootstrapLogLines((prev) => [...prev, line]);
+      });
+
+      unlistenDone = await listen("bootstrap_pipeline_repo:done", (event) => {
+        const payload = event?.payload ?? {};
+        const ok = payload?.ok === true;
+        const message = String(payload?.message ?? "").trim();
+        const suffix = message ? `: ${message}` : "";
+        setBootstrapLogLines((prev) => [...prev, `[done] ${ok ? "ok" : "error"}${suffix}`]);
+      });
+
+      const status = await invoke("bootstrap_pipeline_repo_stream");
+      setPipelineRepoStatus(status ?? null);
+      await Promise.all([loadRuntimeConfig(true), loadPreflight(), loadSettings(), loadPipelineRepoStatus()]);
+    } catch (e) {
+      const msg = String(e);
+      setPipelineRepoError(msg);
+      setBootstrapLogLines((prev) => [...prev, `[error] ${msg}`]);
+      await loadPipelineRepoStatus();
+    } finally {
+      if (typeof unlistenLog === "function") {
+        unlistenLog();
+      }
+      if (typeof unlistenDone === "function") {
+        unlistenDone();
+      }
+      setBootstrapLogBusy(false);
+      setPipelineRepoBusy(false);
+    }
+  }
+
+  async function onUpdatePipelineRepo() {
+    setPipelineRepoBusy(true);
+    setPipelineRepoError("");
+    try {
+      const status = await invoke("update_pipeline_repo");
+      setPipelineRepoStatus(status ?? null);
+      await Promise.all([loadRuntimeConfig(true), loadPreflight(), loadSettings(), loadPipelineRepoStatus()]);
+    } catch (e) {
+      setPipelineRepoError(String(e));
+      await loadPipelineRepoStatus();
+    } finally {
+      setPipelineRepoBusy(false);
+    }
+  }
+
+  async function onValidatePipelineRepo() {
+    setPipelineRepoBusy(true);
+    setPipelineRepoError("");
+    try {
+      const result = await invoke("validate_pipeline_repo");
+      setPipelineRepoValidate(result ?? null);
+    } catch (e) {
+      setPipelineRepoValidate(null);
+      setPipelineRepoError(String(e));
+    } finally {
+      setPipelineRepoBusy(false);
+    }
+  }
+
+  async function onOpenPipelineRepoFolder() {
+    try {
+      await invoke("open_pipeline_repo_folder");
+    } catch (e) {
+      setPipelineRepoError(String(e));
+    }
+  }
+
+  async function onOpenAuditLog() {
+    try {
+      await invoke("open_audit_log");
+    } catch (e) {
+      alert(String(e));
+    }
+  }
+
+  async function tickAutoRetry() {
+    if (autoRetryTickBusyRef.current) return;
+    autoRetryTickBusyRef.current = true;
+    try {
+      const res = await invoke("tick_auto_retry");
+      setTickResult(res ?? null);
+      if (res?.acted) {
+        await Promise.all([loadPipelines(), loadJobs(), loadRuns()]);
+      }
+    } catch (e) {
+      setTickResult({ acted: false, reason: String(e) });
+    } finally {
+      autoRetryTickBusyRef.current = false;
+    }
+  }
+
+  async function loadDiagnostics() {
+    setDiagnosticsLoading(true);
+    setDiagnosticsError("");
+    try {
+      const rows = await invoke("list_diagnostics");
+      const list = Array.isArray(rows) ? rows : [];
+      setDiagnosticsRows(list);
+      setRuntimeCacheStats(await invoke("get_runtime_config_cache_stats").catch(() => null));
+      setRunMetaCacheStats(await invoke("get_run_meta_cache_stats").catch(() => null));
+      setSelectedDiagId((prev) => {
+        if (prev && list.some((r) => r.diag_id === prev)) return prev;
+        return list[0]?.diag_id ?? "";
+      });
+    } catch (e) {
+      setDiagnosticsRows([]);
       setDiagnosticsError(String(e));
     } finally {
       setDiagnosticsLoading(false);
@@ -815,6 +1431,7 @@ export default function App() {
           include_audit: true,
           include_recent_runs: true,
           include_zip: true,
+          redaction: diagRedaction,
         },
       });
       await loadDiagnostics();
@@ -828,6 +1445,79 @@ export default function App() {
     }
   }
 
+  async function onPreviewDiagnostics() {
+    setDiagnosticsError("");
+    try {
+      const res = await invoke("preview_diagnostics", {
+        opts: { include_audit: true, include_recent_runs: true, redaction: diagRedaction },
+      });
+      setDiagPreview(res ?? null);
+    } catch (e) {
+      setDiagnosticsError(String(e));
+    }
+  }
+
+  async function loadAuditEvents() {
+    setDiagnosticsError("");
+    try {
+      const q = auditQuery.trim();
+      // A bare event name (e.g. job_canceled) filters by event, anything else is a text search.
+      const filter = /^[a-z_]+$/.test(q) ? { events: [q] } : { query: q || null };
+      const res = await invoke("list_audit_events", { filter, limit: 200 });
+      setAuditEvents(res?.items ?? []);
+    } catch (e) {
+      setDiagnosticsError(String(e));
+    }
+  }
+
+  async function onRunBenchmarks() {
+    setBenchmarkBusy(true);
+    setDiagnosticsError("");
+    try {
+      const report = await invoke("run_benchmarks", { opts: null });
+      setBenchmarkReport(report ?? null);
+    } catch (e) {
+      setDiagnosticsError(String(e));
+    } finally {
+      setBenchmarkBusy(false);
+    }
+  }
+
+  async function onGenerateSyntheticOutDir() {
+    const raw = window.prompt("Synthetic out_dir: number of runs and profile (mixed | large_graphs | legacy)", "200 mixed");
+    if (!raw) return;
+    const [count, profile] = raw.trim().split(/\s+/);
+    setBenchmarkBusy(true);
+    setDiagnosticsError("");
+    try {
+      const report = await invoke("generate_synthetic_out_dir", {
+        nRuns: Number(count) || 200,
+        profile: profile || "mixed",
+        activate: true,
+      });
+      setSyntheticReport(report ?? null);
+      await loadRuntimeConfig(true);
+      await Promise.all([loadRuns(), loadLibraryRows(), loadJobs()]);
+    } catch (e) {
+      setDiagnosticsError(String(e));
+    } finally {
+      setBenchmarkBusy(false);
+    }
+  }
+
+  async function onRestoreOutDirAfterSynthetic() {
+    if (!syntheticReport?.previous_out_dir) return;
+    try {
+      const res = await invoke("set_config_out_dir", { outDir: syntheticReport.previous_out_dir });
+      setRuntimeCfg(res);
+      if (!res?.ok) throw new Error(res?.message || "Failed to restore out_dir");
+      setSyntheticReport(null);
+      await Promise.all([loadRuns(), loadLibraryRows(), loadJobs()]);
+    } catch (e) {
+      setDiagnosticsError(String(e));
+    }
+  }
+
   async function onGenerateDiagnosticsZipOneClick() {
     setDiagnosticsOneClickBusy(true);
     setDiagnosticsError("");
@@ -838,6 +1528,7 @@ export default function App() {
           include_audit: true,
           include_recent_runs: true,
           include_zip: true,
+          redaction: diagRedaction,
         },
       });
 
@@ -1027,6 +1718,46 @@ export default function App() {
     }
   }
 
+  async function onBackupState() {
+    const destPath = String(stateBundlePath ?? "").trim();
+    if (!destPath) {
+      setWorkspaceError("state bundle path is required");
+      return;
+    }
+    setWorkspaceError("");
+    try {
+      const res = await invoke("backup_state", { destPath });
+      setStateBundlePath(String(res.path));
+      setStateBundleMessage(`Backed up ${res.files.length} file(s) to ${res.path}`);
+    } catch (e) {
+      setWorkspaceError(String(e));
+    }
+  }
+
+  async function onRestoreState() {
+    const srcPath = String(stateBundlePath ?? "").trim();
+    if (!srcPath) {
+      setWorkspaceError("state bundle path is required");
+      return;
+    }
+    setWorkspaceError("");
+    try {
+      const plan = await invoke("restore_state", { srcPath, dryRun: true });
+      const conflicts = plan.conflicts.map((c) => c.message).join("\n");
+      const question = conflicts
+        ? `These files will be replaced (the current ones are backed up):\n${conflicts}\n\nRestore anyway?`
+        : `Restore ${plan.files.join(", ")}?`;
+      if (!window.confirm(question)) return;
+      const res = await invoke("restore_state", { srcPath, overwrite: true });
+      setStateBundleMessage(
+        [`Restored ${res.files.join(", ")}`, ...res.warnings.map((w) => w.message)].join(". "),
+      );
+      await Promise.all([loadPipelines(), loadJobs(), loadRuns(), loadSettings(), loadLibraryRows()]);
+    } catch (e) {
+      setWorkspaceError(String(e));
+    }
+  }
+
   async function onFixRuntimeAfterImport() {
     setWorkspaceFixingRuntime(true);
     setWorkspaceError("");
@@ -1171,9 +1902,9 @@ export default function App() {
     for (let i = 0; i < attempts; i += 1) {
       try {
         const rows = await invoke("list_pipeline_runs", { limit: 200 });
-        const list = Array.isArray(rows) ? rows : [];
+        const list = listResponseItems(rows);
         setPipelineRuns(list);
-        setPipelineRunsError("");
+        setPipelineRunsError(listResponseWarningText(rows));
         const latestRunId = list[0]?.run_id ?? "";
         if (latestRunId) {
           setSelectedPipelineRunId(latestRunId);
@@ -1271,7 +2002,37 @@ export default function App() {
       const pipelineId = await invoke("create_pipeline", {
         name: "Analyze Paper",
         canonicalId: idForRun,
-        steps,
+        steps,
+      });
+      await invoke("start_pipeline", { pipelineId });
+      await loadPipelines();
+      await loadJobs();
+      setSelectedPipelineId(pipelineId);
+      await openRunsAndFocusLatest({ attempts: 6, delayMs: 700, fallbackKind: "input" });
+    } catch (e) {
+      alert(String(e));
+    }
+  }
+
+  async function loadPipelineTemplates() {
+    try {
+      const res = await invoke("list_pipeline_templates");
+      setPipelineTemplates(Array.isArray(res?.templates) ? res.templates : []);
+      setPipelineTemplateWarnings(Array.isArray(res?.warnings) ? res.warnings : []);
+    } catch (e) {
+      setPipelinesError(String(e));
+    }
+  }
+
+  async function onRunPipelineTemplate() {
+    const idForRun = normalized?.canonical?.trim() ? normalized.canonical : paperId;
+    if (!selectedPipelineTemplateId) return;
+    setPipelinesError("");
+    try {
+      const pipelineId = await invoke("create_pipeline_from_template", {
+        templateId: selectedPipelineTemplateId,
+        canonicalId: idForRun,
+        overrides: null,
       });
       await invoke("start_pipeline", { pipelineId });
       await loadPipelines();
@@ -1364,6 +2125,7 @@ export default function App() {
 
   function mapArtifactNameToLegacyKey(name) {
     if (name === "tree.md") return "tree_md";
+    if (name === "summary.md") return "summary_md";
     if (name === "result.json") return "result_json";
     if (name === "input.json") return "input_json";
     if (name === "stdout.log") return "stdout_log";
@@ -1471,8 +2233,9 @@ export default function App() {
       const rows = await invoke("library_list", {
         filters: payload,
       });
-      const list = Array.isArray(rows) ? rows : [];
+      const list = listResponseItems(rows);
       setLibraryRows(list);
+      setLibraryError(listResponseWarningText(rows));
       setSelectedPaperKey((prev) => {
         if (prev && list.some((r) => r.paper_key === prev)) return prev;
         return list[0]?.paper_key ?? "";
@@ -1499,13 +2262,14 @@ export default function App() {
       if (String(nextFilters.status ?? "").trim()) opts.status = nextFilters.status;
       if (String(nextFilters.kind ?? "").trim()) opts.kind = nextFilters.kind;
       if (String(nextFilters.tag ?? "").trim()) opts.tag = nextFilters.tag;
+      if (String(nextFilters.collection ?? "").trim()) opts.collection = nextFilters.collection;
       opts.limit = 300;
 
       const rows = await invoke("library_search", {
         query: normalized,
         opts,
       });
-      const list = Array.isArray(rows) ? rows : [];
+      const list = listResponseItems(rows);
       setLibrarySearchRows(list);
       setSelectedPaperKey((prev) => {
         if (prev && list.some((r) => r.paper_key === prev)) return prev;
@@ -1519,6 +2283,109 @@ export default function App() {
     }
   }
 
+  async function loadSavedSearches() {
+    try {
+      setSavedSearches((await invoke("list_saved_searches")) ?? []);
+    } catch (e) {
+      setLibraryError(String(e));
+    }
+  }
+
+  async function onRunSavedSearch(name) {
+    if (activeSavedSearch === name) {
+      setActiveSavedSearch("");
+      setSavedSearchRows([]);
+      return;
+    }
+    setLibraryError("");
+    try {
+      const rows = await invoke("run_saved_search", { name });
+      const list = listResponseItems(rows);
+      setActiveSavedSearch(name);
+      setSavedSearchRows(list);
+      setLibraryError(listResponseWarningText(rows));
+      if (list.length > 0) setSelectedPaperKey(list[0].paper_key);
+    } catch (e) {
+      setLibraryError(String(e));
+    }
+  }
+
+  async function onSaveCurrentSearch() {
+    const name = String(saveSearchName).trim();
+    if (!name) return;
+    const opts = {};
+    for (const key of ["status", "kind", "tag", "collection"]) {
+      if (String(libraryFilters[key] ?? "").trim()) opts[key] = libraryFilters[key];
+    }
+    try {
+      await invoke("save_search", { name, query: String(librarySearchQuery ?? ""), opts });
+      setSaveSearchName("");
+      await loadSavedSearches();
+    } catch (e) {
+      setLibraryError(String(e));
+    }
+  }
+
+  async function onDeleteSavedSearch(name) {
+    try {
+      await invoke("delete_saved_search", { name });
+      if (activeSavedSearch === name) {
+        setActiveSavedSearch("");
+        setSavedSearchRows([]);
+      }
+      await loadSavedSearches();
+    } catch (e) {
+      setLibraryError(String(e));
+    }
+  }
+
+  async function loadCollections() {
+    try {
+      setCollections((await invoke("list_collections")) ?? []);
+    } catch (e) {
+      setLibraryError(String(e));
+    }
+  }
+
+  async function onCreateCollection() {
+    const name = String(newCollectionName).trim();
+    if (!name) return;
+    try {
+      await invoke("create_collection", { name, position: null });
+      setNewCollectionName("");
+      await loadCollections();
+    } catch (e) {
+      setLibraryError(String(e));
+    }
+  }
+
+  async function onMoveCollection(collectionId, delta) {
+    const ids = collections.map((c) => c.collection_id);
+    const from = ids.indexOf(collectionId);
+    const to = from + delta;
+    if (from < 0 || to < 0 || to >= ids.length) return;
+    ids.splice(to, 0, ids.splice(from, 1)[0]);
+    try {
+      setCollections((await invoke("reorder_collections", { collectionIds: ids })) ?? []);
+    } catch (e) {
+      setLibraryError(String(e));
+    }
+  }
+
+  async function onToggleCollectionMembership(collection) {
+    if (!selectedPaperKey) return;
+    const member = (collection.paper_keys ?? []).includes(selectedPaperKey);
+    try {
+      await invoke(member ? "remove_from_collection" : "add_to_collection", {
+        collectionId: collection.collection_id,
+        paperKeys: [selectedPaperKey],
+      });
+      await loadCollections();
+    } catch (e) {
+      setLibraryError(String(e));
+    }
+  }
+
   async function loadLibraryStats() {
     try {
       const stats = await invoke("library_stats");
@@ -1537,52 +2404,429 @@ export default function App() {
       const rec = await invoke("library_get", { paperKey });
       setLibraryDetail(rec);
       setTagInput(Array.isArray(rec?.tags) ? rec.tags.join(", ") : "");
+      setMetaTitleInput(rec?.title ?? "");
+      setMetaYearInput(rec?.year != null ? String(rec.year) : "");
+      setMetaCanonicalInput(rec?.canonical_id ?? "");
+    } catch (e) {
+      setLibraryDetail(null);
+      setLibraryError(String(e));
+    }
+  }
+
+  async function onSearchArtifacts() {
+    const query = artifactSearchQuery.trim();
+    if (!query) {
+      setArtifactSearchHits(null);
+      return;
+    }
+    setArtifactSearchLoading(true);
+    try {
+      const res = await invoke("search_artifacts", { query, opts: { limit: 50 } });
+      setArtifactSearchHits(listResponseItems(res));
+      const warn = listResponseWarningText(res);
+      if (warn) setLibraryError(warn);
+    } catch (e) {
+      setArtifactSearchHits([]);
+      setLibraryError(String(e));
+    } finally {
+      setArtifactSearchLoading(false);
+    }
+  }
+
+  async function onLibraryExport() {
+    const ext = libraryExportFormat === "bibtex" ? "bib" : libraryExportFormat;
+    try {
+      const selected = await saveDialog({
+        title: "Export library",
+        defaultPath: `library.${ext}`,
+        filters: [{ name: libraryExportFormat.toUpperCase(), extensions: [ext] }],
+      });
+      if (typeof selected !== "string") return;
+      const filters = {};
+      for (const [k, v] of Object.entries(libraryFilters)) {
+        if (String(v ?? "").trim() !== "") {
+          filters[k] = v;
+        }
+      }
+      const res = await invoke("library_export", { format: libraryExportFormat, path: selected, filters });
+      setLibraryExportInfo(res);
+    } catch (e) {
+      alert(String(e));
+    }
+  }
+
+  async function onLibraryExportBundle() {
+    try {
+      const selected = await saveDialog({
+        title: "Export library sync bundle",
+        defaultPath: "library_bundle.json",
+        filters: [{ name: "JSON", extensions: ["json"] }],
+      });
+      if (typeof selected !== "string") return;
+      const res = await invoke("library_export_bundle", { path: selected });
+      setLibraryExportInfo({ count: res.papers, path: res.path });
+    } catch (e) {
+      alert(String(e));
+    }
+  }
+
+  async function onLibraryImportBundle() {
+    setLibraryError("");
+    try {
+      const selected = await openDialog({
+        multiple: false,
+        title: "Select library_bundle.json",
+        filters: [{ name: "JSON", extensions: ["json"] }],
+      });
+      if (typeof selected !== "string") return;
+      const args = { path: selected, mergeStrategy: libraryMergeStrategy };
+      const preview = await invoke("library_import_bundle", { ...args, dryRun: true });
+      const conflicts = preview.conflicts.map((c) => `${c.paper_key} ${c.field}: ${c.message} (${c.resolution})`);
+      const summary =
+        `${preview.matched} matching papers, ${preview.updated.length} updated, ` +
+        `${preview.notes_written.length} notes, ${preview.unmatched.length} not in this library.`;
+      const details = conflicts.length > 0 ? `\n\nConflicts:\n${conflicts.slice(0, 20).join("\n")}` : "";
+      if (!window.confirm(`${summary}${details}\n\nMerge (${libraryMergeStrategy})?`)) return;
+      await invoke("library_import_bundle", args);
+      await Promise.all([loadLibraryRows(), loadCollections(), loadLibraryStats()]);
+    } catch (e) {
+      setLibraryError(String(e));
+    }
+  }
+
+  async function onLibraryReindex() {
+    setLibraryLoading(true);
+    setLibraryError("");
+    try {
+      const info = await invoke("library_reindex", { full: true, scanId: LIBRARY_REINDEX_SCAN_ID });
+      await invoke("library_reload");
+      setLibraryReindexInfo(info);
+      if (String(librarySearchQuery).trim()) {
+        await loadLibrarySearch(librarySearchQuery, libraryFilters);
+      } else {
+        await loadLibraryRows(libraryFilters);
+      }
+      await loadLibraryStats();
+    } catch (e) {
+      setLibraryError(String(e));
+    } finally {
+      setLibraryLoading(false);
+    }
+  }
+
+  async function onImportExternalRuns() {
+    setLibraryError("");
+    try {
+      const selected = await openDialog({
+        directory: true,
+        multiple: false,
+        title: "Select colleague out_dir",
+      });
+      if (typeof selected !== "string") return;
+      const preview = await invoke("import_external_runs", {
+        path: selected,
+        filter: { ...externalRunFilter, dry_run: true },
+      });
+      const count = (preview?.imported ?? []).length;
+      if (count === 0) {
+        setExternalImportResult(preview);
+        return;
+      }
+      if (!window.confirm(`Import ${count} run(s) from ${preview.source_dir}?`)) return;
+      setLibraryLoading(true);
+      const res = await invoke("import_external_runs", { path: selected, filter: externalRunFilter });
+      setExternalImportResult(res);
+      await loadLibraryRows(libraryFilters);
+      await loadLibraryStats();
+    } catch (e) {
+      setLibraryError(String(e));
+    } finally {
+      setLibraryLoading(false);
+    }
+  }
+
+  async function loadEnrichStatus() {
+    try {
+      const status = await invoke("get_enrich_status");
+      setEnrichStatus(status ?? null);
+    } catch (e) {
+      setLibraryError(String(e));
+    }
+  }
+
+  async function onEnrichAll() {
+    setLibraryError("");
+    try {
+      const status = await invoke("enrich_all", {
+        filter: { ...libraryFilters, only_missing: true, s2: enrichFromS2 },
+        budget: null,
+        resume: true,
+      });
+      setEnrichStatus(status ?? null);
+    } catch (e) {
+      setLibraryError(String(e));
+    }
+  }
+
+  async function onSaveTags() {
+    if (!selectedPaperKey) return;
+    const tags = tagInput
+      .split(",")
+      .map((s) => s.trim())
+      .filter(Boolean);
+    try {
+      const updated = await invoke("library_set_tags", {
+        paperKey: selectedPaperKey,
+        tags,
+      });
+      setLibraryDetail(updated);
+      if (String(librarySearchQuery).trim()) {
+        await loadLibrarySearch(librarySearchQuery, libraryFilters);
+      } else {
+        await loadLibraryRows(libraryFilters);
+      }
+      await loadLibraryStats();
+    } catch (e) {
+      setLibraryError(String(e));
+    }
+  }
+
+  async function onEnrichFromS2() {
+    if (!selectedPaperKey) return;
+    setLibraryError("");
+    try {
+      const updated = await invoke("library_enrich", { paperKey: selectedPaperKey });
+      setLibraryDetail(updated);
+    } catch (e) {
+      setLibraryError(String(e));
+    }
+  }
+
+  async function onMergeLibraryRecord() {
+    const secondary = String(mergeKeyInput).trim();
+    if (!selectedPaperKey || !secondary) return;
+    if (!confirm(`Merge ${secondary} into ${selectedPaperKey}?`)) return;
+    try {
+      const merged = await invoke("library_merge", {
+        paperKeyPrimary: selectedPaperKey,
+        paperKeySecondary: secondary,
+      });
+      setLibraryDetail(merged);
+      setMergeKeyInput("");
+      if (String(librarySearchQuery).trim()) {
+        await loadLibrarySearch(librarySearchQuery, libraryFilters);
+      } else {
+        await loadLibraryRows(libraryFilters);
+      }
+    } catch (e) {
+      setLibraryError(String(e));
+    }
+  }
+
+  async function onSaveMetadata() {
+    if (!selectedPaperKey) return;
+    const patch = { title: metaTitleInput };
+    const year = String(metaYearInput).trim();
+    if (year) {
+      patch.year = Number(year);
+    }
+    const canonical = String(metaCanonicalInput).trim();
+    if (canonical !== String(libraryDetail?.canonical_id ?? "")) {
+      patch.canonical_id = canonical;
+    }
+    try {
+      const updated = await invoke("library_update_metadata", {
+        paperKey: selectedPaperKey,
+        patch,
+      });
+      setLibraryDetail(updated);
+      if (String(librarySearchQuery).trim()) {
+        await loadLibrarySearch(librarySearchQuery, libraryFilters);
+      } else {
+        await loadLibraryRows(libraryFilters);
+      }
+    } catch (e) {
+      setLibraryError(String(e));
+    }
+  }
+
+  async function onCopyGraphAsDot(nodeIds) {
+    if (!selectedRunId) return;
+    setGraphDotStatus("");
+    try {
+      const res = await invoke("copy_graph_as_dot", {
+        runId: selectedRunId,
+        name: selectedArtifact,
+        opts: nodeIds ? { node_ids: nodeIds } : null,
+      });
+      if (!res?.copied) {
+        await navigator.clipboard.writeText(String(res?.dot ?? ""));
+      }
+      setGraphDotStatus(`copied DOT: nodes=${res?.nodes_count ?? 0} edges=${res?.edges_count ?? 0}`);
+    } catch (e) {
+      setGraphDotStatus(String(e));
+    }
+  }
+
+  async function onAnalyzeGraph() {
+    if (!graphParsed) return;
+    setGraphDotStatus("");
+    try {
+      // A merged graph only exists in memory, so send it as content.
+      const args = graphMergeInfo
+        ? {
+            content: JSON.stringify({
+              nodes: graphParsed.nodes.map((n) => ({ id: n.id, label: n.label })),
+              edges: graphParsed.edges.map((e) => ({ source: e.source, target: e.target })),
+            }),
+          }
+        : { runId: selectedRunId, name: selectedArtifact };
+      const res = await invoke("analyze_graph", { ...args, opts: { top_n: 10 } });
+      setGraphAnalysis(res ?? null);
+    } catch (e) {
+      setGraphDotStatus(String(e));
+    }
+  }
+
+  async function onEgoNetwork() {
+    if (!graphParsed || !selectedGraphNodeId) return;
+    const raw = window.prompt(`Ego network around ${selectedGraphNodeId}: hops`, "1");
+    if (raw === null) return;
+    setGraphDotStatus("");
+    try {
+      const args = graphMergeInfo
+        ? {
+            content: JSON.stringify({
+              nodes: graphParsed.nodes.map((n) => ({ id: n.id, label: n.label, type: n.node_type, year: n.year, score: n.score })),
+              edges: graphParsed.edges.map((e) => ({ source: e.source, target: e.target, type: e.edge_type, weight: e.weight })),
+            }),
+          }
+        : { runId: selectedRunId, name: selectedArtifact };
+      const opts = {
+        seed: selectedGraphNodeId,
+        hops: Number(raw) || 0,
+        year_from: graphYearFrom ? Number(graphYearFrom) : null,
+        year_to: graphYearTo ? Number(graphYearTo) : null,
+        node_types: graphTypeFilter === "all" ? null : [graphTypeFilter],
+      };
+      const res = await invoke("filter_graph", { ...args, opts });
+      setGraphParsed(res?.graph ?? null);
+      setGraphAnalysis(null);
+      setGraphDotStatus(
+        `ego network: ${res?.graph?.nodes?.length ?? 0} nodes${res?.truncated ? ` (of ${res.matched_nodes})` : ""}`,
+      );
+    } catch (e) {
+      setGraphDotStatus(String(e));
+    }
+  }
+
+  async function onMergeGraphs() {
+    if (!selectedRunId) return;
+    const raw = window.prompt("Merge this graph with runs (comma-separated run ids)", "");
+    if (raw === null) return;
+    const others = raw
+      .split(/[\s,]+/)
+      .map((v) => v.trim())
+      .filter(Boolean);
+    if (others.length === 0) return;
+    setGraphDotStatus("");
+    try {
+      const res = await invoke("merge_graphs", { runIds: [selectedRunId, ...others] });
+      setGraphParsed(res?.graph ?? null);
+      setGraphMergeInfo(res ?? null);
+      setGraphAnalysis(null);
+      setSelectedGraphNodeId(res?.graph?.nodes?.[0]?.id ?? "");
+      const failed = (res?.sources ?? []).filter((src) => src.error);
+      if (failed.length > 0) {
+        setGraphDotStatus(`skipped: ${failed.map((src) => `${src.run_id} (${src.error})`).join(", ")}`);
+      }
+    } catch (e) {
+      setGraphDotStatus(String(e));
+    }
+  }
+
+  async function onExportRunBibtex() {
+    if (!selectedRunId) return;
+    setGraphDotStatus("");
+    try {
+      const res = await invoke("export_run_bibtex", {
+        runId: selectedRunId,
+        name: selectedArtifact,
+      });
+      const skipped = (res?.skipped ?? []).length;
+      setGraphDotStatus(
+        `BibTeX: entries=${res?.entries_count ?? 0} library=${res?.from_library ?? 0} skipped=${skipped} -> ${res?.path ?? ""}`
+      );
+    } catch (e) {
+      setGraphDotStatus(String(e));
+    }
+  }
+
+  async function reloadArtifactAnnotations() {
+    if (!selectedRunId || !artifactView?.artifact) return;
+    try {
+      const annotations = await invoke("list_artifact_annotations", {
+        runId: selectedRunId,
+        name: artifactView.artifact,
+      });
+      setArtifactView((prev) => (prev ? { ...prev, annotations: annotations ?? [] } : prev));
+    } catch (e) {
+      setAnnotationStatus(String(e));
+    }
+  }
+
+  async function onAddArtifactAnnotation() {
+    if (!selectedRunId || !artifactView?.artifact) return;
+    setAnnotationStatus("");
+    const anchor =
+      annotationDraft.type === "node"
+        ? { type: "node", node_id: annotationDraft.nodeId || selectedGraphNodeId }
+        : {
+            type: "lines",
+            start: Number(annotationDraft.start),
+            end: Number(annotationDraft.end || annotationDraft.start),
+          };
+    try {
+      const added = await invoke("add_artifact_annotation", {
+        runId: selectedRunId,
+        name: artifactView.artifact,
+        anchor,
+        text: annotationDraft.text,
+      });
+      setArtifactView((prev) =>
+        prev ? { ...prev, annotations: [...(prev.annotations ?? []), added] } : prev
+      );
+      setAnnotationDraft((prev) => ({ ...prev, text: "" }));
     } catch (e) {
-      setLibraryDetail(null);
-      setLibraryError(String(e));
+      setAnnotationStatus(String(e));
     }
   }
 
-  async function onLibraryReindex() {
-    setLibraryLoading(true);
-    setLibraryError("");
+  async function onDeleteArtifactAnnotation(annotationId) {
+    if (!selectedRunId) return;
     try {
-      const info = await invoke("library_reindex", { full: true });
-      await invoke("library_reload");
-      setLibraryReindexInfo(info);
-      if (String(librarySearchQuery).trim()) {
-        await loadLibrarySearch(librarySearchQuery, libraryFilters);
-      } else {
-        await loadLibraryRows(libraryFilters);
-      }
-      await loadLibraryStats();
+      await invoke("delete_artifact_annotation", { runId: selectedRunId, annotationId });
+      setArtifactView((prev) =>
+        prev
+          ? { ...prev, annotations: (prev.annotations ?? []).filter((a) => a.annotation_id !== annotationId) }
+          : prev
+      );
     } catch (e) {
-      setLibraryError(String(e));
-    } finally {
-      setLibraryLoading(false);
+      setAnnotationStatus(String(e));
     }
   }
 
-  async function onSaveTags() {
-    if (!selectedPaperKey) return;
-    const tags = tagInput
-      .split(",")
-      .map((s) => s.trim())
-      .filter(Boolean);
+  async function onExportArtifactHtml() {
+    if (!selectedRunId || !artifactView?.artifact) return;
+    setAnnotationStatus("");
     try {
-      const updated = await invoke("library_set_tags", {
-        paperKey: selectedPaperKey,
-        tags,
+      const res = await invoke("export_artifact_html", {
+        runId: selectedRunId,
+        name: artifactView.artifact,
       });
-      setLibraryDetail(updated);
-      if (String(librarySearchQuery).trim()) {
-        await loadLibrarySearch(librarySearchQuery, libraryFilters);
-      } else {
-        await loadLibraryRows(libraryFilters);
-      }
-      await loadLibraryStats();
+      setAnnotationStatus(`HTML export: annotations=${res?.annotations_count ?? 0} -> ${res?.path ?? ""}`);
     } catch (e) {
-      setLibraryError(String(e));
+      setAnnotationStatus(String(e));
     }
   }
 
@@ -1601,6 +2845,30 @@ export default function App() {
     setSelectedArtifact(key);
   }
 
+  async function onSetPrimaryViz(item) {
+    if (!selectedRunId) return;
+    try {
+      await invoke("set_primary_viz", { runId: selectedRunId, name: item?.name ?? "" });
+      await loadRuns();
+    } catch (e) {
+      setRunArtifactCatalogError(String(e));
+    }
+  }
+
+  async function onSearchRunArtifacts() {
+    const query = artifactSearchQuery.trim();
+    if (!selectedRunId || !query) {
+      setArtifactSearchResult(null);
+      return;
+    }
+    try {
+      const res = await invoke("search_run_artifacts", { runId: selectedRunId, query, maxMatches: null });
+      setArtifactSearchResult({ items: listResponseItems(res), warning: listResponseWarningText(res) });
+    } catch (e) {
+      setArtifactSearchResult({ items: [], warning: String(e) });
+    }
+  }
+
   async function onOpenCatalogArtifact(item) {
     if (!selectedRunId || !item?.name) return;
     const key = mapArtifactNameToLegacyKey(item.name);
@@ -1610,6 +2878,16 @@ export default function App() {
       return;
     }
 
+    if (item.kind === "graph_json" && (item.size_bytes ?? 0) > GRAPH_SESSION_THRESHOLD_BYTES) {
+      await onOpenGraphSession(item);
+      return;
+    }
+
+    if (item.kind === "image" || item.kind === "svg") {
+      await onOpenImageArtifact(item);
+      return;
+    }
+
     setArtifactLoading(true);
     setArtifactError("");
     try {
@@ -1625,6 +2903,7 @@ export default function App() {
         kind: named?.kind ?? item.kind ?? "text",
         content: named?.content ?? "",
         parse_status: named?.truncated ? "truncated" : "ok",
+        annotations: Array.isArray(named?.annotations) ? named.annotations : [],
       });
       setArtifactWarnings(Array.isArray(named?.warnings) ? named.warnings : []);
     } catch (e) {
@@ -1636,6 +2915,148 @@ export default function App() {
     }
   }
 
+  async function onEnableInteractiveHtml() {
+    if (!artifactView?.run_id || !artifactView?.artifact) return;
+    const ok = window.confirm(
+      `Run the scripts in HTML artifacts of run ${artifactView.run_id}?\n\n` +
+        "Only inline module scripts from the artifact run, without network access. " +
+        "Confirm only for runs produced by a pipeline you trust."
+    );
+    if (!ok) return;
+    setArtifactLoading(true);
+    setArtifactError("");
+    try {
+      await invoke("set_run_html_trust", { runId: artifactView.run_id, trusted: true });
+      const view = await invoke("read_run_artifact_interactive", {
+        runId: artifactView.run_id,
+        name: artifactView.artifact,
+      });
+      setArtifactView((prev) => ({ ...prev, interactive_content: view?.content ?? "" }));
+      setArtifactWarnings(Array.isArray(view?.warnings) ? view.warnings : []);
+    } catch (e) {
+      setArtifactError(String(e));
+    } finally {
+      setArtifactLoading(false);
+    }
+  }
+
+  async function onReadArtifactRange() {
+    if (!artifactView?.run_id || !artifactView?.artifact) return;
+    const offset = artifactView.range_next ?? 0;
+    setArtifactLoading(true);
+    setArtifactError("");
+    try {
+      const range = await invoke("read_run_artifact_range", {
+        runId: artifactView.run_id,
+        name: artifactView.artifact,
+        offset,
+        length: ARTIFACT_RANGE_BYTES,
+      });
+      setArtifactView((prev) => ({
+        ...prev,
+        content: offset === 0 ? range.content : `${prev.content}${range.content}`,
+        range_next: range.next_offset,
+        range_total: range.total_bytes,
+        parse_status: range.eof ? "ok" : "truncated",
+      }));
+    } catch (e) {
+      setArtifactError(String(e));
+    } finally {
+      setArtifactLoading(false);
+    }
+  }
+
+  async function onOpenImageArtifact(item) {
+    setArtifactLoading(true);
+    setArtifactError("");
+    try {
+      const bin = await invoke("read_run_artifact_binary", {
+        runId: selectedRunId,
+        name: item.name,
+        maxBytes: null,
+      });
+      setArtifactView({
+        run_id: selectedRunId,
+        artifact: item.name,
+        path: item.rel_path,
+        exists: true,
+        kind: item.kind,
+        content: bin?.data_base64 ? `data:${bin.mime_type};base64,${bin.data_base64}` : "",
+        parse_status: bin?.data_base64 ? "ok" : "truncated",
+        annotations: [],
+      });
+      setArtifactWarnings(
+        bin?.temp_path ? [`image is too large to preview inline; copied to ${bin.temp_path}`] : []
+      );
+    } catch (e) {
+      setArtifactView(null);
+      setArtifactWarnings([]);
+      setArtifactError(String(e));
+    } finally {
+      setArtifactLoading(false);
+    }
+  }
+
+  async function onOpenGraphSession(item) {
+    setArtifactLoading(true);
+    setArtifactError("");
+    try {
+      if (graphSession?.session_id) {
+        await invoke("close_graph_session", { sessionId: graphSession.session_id }).catch(() => {});
+      }
+      const info = await invoke("open_graph_session", { runId: selectedRunId, name: item.name });
+      setGraphSession(info);
+      setArtifactView({
+        run_id: selectedRunId,
+        artifact: item.name,
+        path: item.rel_path,
+        exists: true,
+        kind: "graph_json",
+        content: "",
+        graph_session: info.session_id,
+        parse_status: "session",
+      });
+      setArtifactWarnings(Array.isArray(info?.warnings) ? info.warnings : []);
+      await onQueryGraphSlice(info);
+    } catch (e) {
+      setGraphSession(null);
+      setArtifactView(null);
+      setArtifactError(String(e));
+    } finally {
+      setArtifactLoading(false);
+    }
+  }
+
+  async function onQueryGraphSlice(session = graphSession) {
+    if (!session?.session_id) return;
+    setGraphParseLoading(true);
+    setGraphParseError("");
+    try {
+      const slice = await invoke("graph_query", {
+        sessionId: session.session_id,
+        query: {
+          query: graphQuery.trim() || null,
+          node_types: graphTypeFilter !== "all" ? [graphTypeFilter] : null,
+          year_from: graphYearFrom ? Number(graphYearFrom) : null,
+          year_to: graphYearTo ? Number(graphYearTo) : null,
+        },
+      });
+      setGraphParsed({
+        nodes: slice?.nodes ?? [],
+        edges: slice?.edges ?? [],
+        stats: slice?.stats ?? {},
+        warnings: session.warnings ?? [],
+        slice: { matched: slice?.matched_nodes ?? 0, truncated: !!slice?.truncated },
+      });
+      setSelectedGraphNodeId(slice?.nodes?.[0]?.id ?? "");
+    } catch (e) {
+      setGraphParsed(null);
+      setGraphParseError(String(e));
+    } finally {
+      setGraphParseLoading(false);
+    }
+  }
+
   async function onOpenNamedArtifactForRun(runIdFromRow, itemName) {
     if (!runIdFromRow || !itemName) return;
     await loadRuns();
@@ -1663,6 +3084,7 @@ export default function App() {
         kind: named?.kind ?? "text",
         content: named?.content ?? "",
         parse_status: named?.truncated ? "truncated" : "ok",
+        annotations: Array.isArray(named?.annotations) ? named.annotations : [],
       });
       setArtifactWarnings(Array.isArray(named?.warnings) ? named.warnings : []);
     } catch (e) {
@@ -1676,8 +3098,12 @@ export default function App() {
 
   useEffect(() => {
     loadRuntimeConfig(false);
+    loadStartupIssues();
+    loadDevCommandsEnabled();
     loadPreflight();
     loadTemplates();
+    loadCollections();
+    loadSavedSearches();
     loadRuns();
     loadRunDashboardStats();
     loadPipelineRuns();
@@ -1692,13 +3118,142 @@ export default function App() {
   }, []);
 
   useEffect(() => {
-    const timer = setInterval(() => {
-      loadJobs();
-      loadPipelines();
-    }, 1500);
-    return () => clearInterval(timer);
+    if (!selectedTemplateId) {
+      setTemplateParamCheck(null);
+      return undefined;
+    }
+    const canonicalId = normalized?.canonical?.trim() ? normalized.canonical : paperId;
+    const timer = setTimeout(async () => {
+      try {
+        const res = await invoke("validate_template_params", {
+          templateId: selectedTemplateId,
+          canonicalId,
+          params: templateParams ?? {},
+        });
+        setTemplateParamCheck(res);
+      } catch {
+        setTemplateParamCheck(null);
+      }
+    }, 300);
+    return () => clearTimeout(timer);
+  }, [selectedTemplateId, templateParams, normalized?.canonical, paperId]);
+
+  useEffect(() => {
+    // Poll cadence follows get_refresh_hints; hints themselves are re-read at most every 5s.
+    let cancelled = false;
+    let timer = null;
+    const last = { jobs: 0, pipelines: 0, runs: 0 };
+    const due = (view, hints, fallbackMs) => {
+      const ms = hints ? hints.views?.[view]?.interval_ms : fallbackMs;
+      if (ms == null || Date.now() - last[view] < ms) return false;
+      last[view] = Date.now();
+      return true;
+    };
+    const tick = async () => {
+      let hints = null;
+      try {
+        hints = await invoke("get_refresh_hints");
+        setRefreshHints(hints);
+        if (hints?.library_updated_at) setLibraryUpdatedAt(hints.library_updated_at);
+      } catch {
+        hints = null;
+      }
+      if (due("jobs", hints, 1500)) loadJobs();
+      if (due("pipelines", hints, 1500)) loadPipelines();
+      if (due("runs", hints, null)) setRunsPollTick((n) => n + 1);
+      if (cancelled) return;
+      const jobsMs = hints?.views?.jobs?.interval_ms ?? 1500;
+      timer = setTimeout(tick, Math.min(jobsMs, 5000));
+    };
+    tick();
+    return () => {
+      cancelled = true;
+      if (timer) clearTimeout(timer);
+    };
+  }, []);
+
+  useEffect(() => {
+    if (runsPollTick > 0) loadRuns();
+  }, [runsPollTick]);
+
+  useEffect(() => {
+    let unlisten = null;
+    let disposed = false;
+    listen("job://progress", (event) => {
+      const ev = event?.payload ?? {};
+      if (ev.kind === "status") {
+        setJobs((prev) =>
+          prev.map((j) =>
+            j.job_id === ev.job_id
+              ? { ...j, status: ev.status, run_id: ev.run_id ?? j.run_id, last_error: ev.message ?? j.last_error, updated_at: ev.at }
+              : j
+          )
+        );
+        if (ev.status === "queued") loadJobs();
+        if (ev.status !== "running" && ev.status !== "queued") setRunsPollTick((n) => n + 1);
+      } else if (ev.kind === "output") {
+        setJobLiveOutput((prev) => {
+          const lines = [...(prev[ev.job_id] ?? []), ...(ev.lines ?? []).map((l) => `${ev.stream === "stderr" ? "! " : ""}${l}`)];
+          return { ...prev, [ev.job_id]: lines.slice(-200) };
+        });
+      } else if (ev.kind === "pipeline_step") {
+        loadPipelines();
+      }
+    }).then((fn) => {
+      if (disposed) fn();
+      else unlisten = fn;
+    });
+    return () => {
+      disposed = true;
+      if (typeof unlisten === "function") unlisten();
+    };
+  }, []);
+
+  useEffect(() => {
+    let unlisten = null;
+    let disposed = false;
+    listen("app://shutdown", (event) => setShutdownStatus(event?.payload ?? null)).then((fn) => {
+      if (disposed) fn();
+      else unlisten = fn;
+    });
+    return () => {
+      disposed = true;
+      if (typeof unlisten === "function") unlisten();
+    };
+  }, []);
+
+  useEffect(() => {
+    let unlisten = null;
+    let disposed = false;
+    listen("config://changed", () => loadRuntimeConfig()).then((fn) => {
+      if (disposed) fn();
+      else unlisten = fn;
+    });
+    return () => {
+      disposed = true;
+      if (typeof unlisten === "function") unlisten();
+    };
   }, []);
 
+  useEffect(() => {
+    if (shutdownStatus?.phase !== "draining") return;
+    const timer = setInterval(async () => {
+      try {
+        setShutdownStatus(await invoke("shutdown_status"));
+      } catch {
+        // the backend may already be exiting
+      }
+    }, 500);
+    return () => clearInterval(timer);
+  }, [shutdownStatus?.phase]);
+
+  useEffect(() => {
+    if (!libraryUpdatedAt) return;
+    const seen = seenLibraryUpdatedAtRef.current;
+    seenLibraryUpdatedAtRef.current = libraryUpdatedAt;
+    if (seen && seen !== libraryUpdatedAt) loadLibraryRows();
+  }, [libraryUpdatedAt]);
+
   useEffect(() => {
     const onPopState = () => {
       setActiveScreen(screenFromPathname(window.location.pathname));
@@ -1723,11 +3278,60 @@ export default function App() {
     loadPipelineRepoStatus();
     loadDiagnostics();
     loadWorkspaceHistory();
+    // The backend scheduler retries on its own unless it is switched off.
+    if (desktopSettings?.auto_retry_scheduler_enabled !== false) return;
     const timer = setInterval(() => {
       tickAutoRetry();
     }, 2000);
     return () => clearInterval(timer);
-  }, [activeScreen]);
+  }, [activeScreen, desktopSettings?.auto_retry_scheduler_enabled]);
+
+  useEffect(() => {
+    let unlisten = null;
+    let disposed = false;
+    loadNotifications();
+    listen("notification://new", () => loadNotifications()).then((fn) => {
+      if (disposed) fn();
+      else unlisten = fn;
+    });
+    return () => {
+      disposed = true;
+      if (typeof unlisten === "function") unlisten();
+    };
+  }, []);
+
+  useEffect(() => {
+    let unlisten = null;
+    let disposed = false;
+    loadQuarantinedFiles();
+    listen("state://quarantined", () => {
+      loadQuarantinedFiles();
+      loadStartupIssues();
+    }).then((fn) => {
+      if (disposed) fn();
+      else unlisten = fn;
+    });
+    return () => {
+      disposed = true;
+      if (typeof unlisten === "function") unlisten();
+    };
+  }, []);
+
+  useEffect(() => {
+    let unlisten = null;
+    let disposed = false;
+    listen("auto_retry://enqueued", (event) => {
+      setTickResult(event?.payload ?? null);
+      Promise.all([loadPipelines(), loadJobs(), loadRuns()]);
+    }).then((fn) => {
+      if (disposed) fn();
+      else unlisten = fn;
+    });
+    return () => {
+      disposed = true;
+      if (typeof unlisten === "function") unlisten();
+    };
+  }, []);
 
   useEffect(() => {
     if (activeScreen !== "runs") return;
@@ -1763,7 +3367,7 @@ export default function App() {
       loadLibrarySearch(q, libraryFilters);
     }, 260);
     return () => clearTimeout(timer);
-  }, [librarySearchQuery, libraryFilters.status, libraryFilters.kind, libraryFilters.tag]);
+  }, [librarySearchQuery, libraryFilters.status, libraryFilters.kind, libraryFilters.tag, libraryFilters.collection]);
 
   const selectedTemplate = templates.find((t) => t.id === selectedTemplateId) ?? null;
 
@@ -1803,6 +3407,23 @@ export default function App() {
     }
   }
 
+  async function resolveIdentifierQuery() {
+    setIdentifierResolving(true);
+    try {
+      const res = await invoke("resolve_identifier", { query: paperId });
+      setIdentifierResolution(res ?? null);
+    } catch (e) {
+      setIdentifierResolution({ candidates: [], errors: [String(e)] });
+    } finally {
+      setIdentifierResolving(false);
+    }
+  }
+
+  function applyResolvedCandidate(candidate) {
+    setPaperId(candidate.kind === "doi" ? `doi:${candidate.canonical}` : candidate.canonical);
+    setIdentifierResolution(null);
+  }
+
   useEffect(() => {
     const timer = setTimeout(async () => {
       setNormalizeLoading(true);
@@ -1831,6 +3452,7 @@ export default function App() {
 
   useEffect(() => {
     loadSelectedRunArtifactCatalog(selectedRunId);
+    loadRunNote(selectedRunId);
   }, [selectedRunId]);
 
   useEffect(() => {
@@ -1875,6 +3497,9 @@ export default function App() {
 
   useEffect(() => {
     const kind = artifactView?.kind ?? "";
+    if (artifactView?.graph_session) return;
+    setGraphMergeInfo(null);
+    setGraphAnalysis(null);
     if (kind !== "graph_json" || !artifactView?.content) {
       setGraphParsed(null);
       setGraphParseLoading(false);
@@ -1937,6 +3562,8 @@ export default function App() {
         templateId: params.templateId,
         canonicalId: params.canonicalId,
         params: params.templateParams,
+        envOverrides: params.envOverrides ?? null,
+        extraArgs: params.extraArgs ?? null,
       });
 
       setStdout(`enqueued job_id=${jobId}`);
@@ -1952,21 +3579,131 @@ export default function App() {
       setSelectedJobId(jobId);
       await openRunsAndFocusLatest({ attempts: 8, delayMs: 500, fallbackKind: "warnings" });
     } catch (e) {
-      setStderr(String(e));
-      setStatus("error");
-      setMessage(String(e));
-    } finally {
-      setRunning(false);
+      setStderr(String(e));
+      setStatus("error");
+      setMessage(String(e));
+    } finally {
+      setRunning(false);
+    }
+  }
+
+  function parseJobOverridesDraft() {
+    const envOverrides = {};
+    for (const line of jobEnvOverridesDraft.split("\n")) {
+      const trimmed = line.trim();
+      if (!trimmed) continue;
+      const eq = trimmed.indexOf("=");
+      if (eq <= 0) {
+        throw new Error(`env override must be KEY=VALUE: ${trimmed}`);
+      }
+      envOverrides[trimmed.slice(0, eq).trim()] = trimmed.slice(eq + 1).trim();
+    }
+    const extraArgs = jobExtraArgsDraft.split(/\s+/).filter(Boolean);
+    return {
+      envOverrides: Object.keys(envOverrides).length ? envOverrides : null,
+      extraArgs: extraArgs.length ? extraArgs : null,
+    };
+  }
+
+  async function onRunTree() {
+    const idForRun = normalized?.canonical?.trim() ? normalized.canonical : paperId;
+    let overrides;
+    try {
+      overrides = parseJobOverridesDraft();
+    } catch (e) {
+      alert(e.message);
+      return;
+    }
+    await runTree({
+      templateId: selectedTemplateId,
+      canonicalId: idForRun,
+      templateParams,
+      ...overrides,
+    });
+  }
+
+  async function onDryRunTemplate() {
+    const idForRun = normalized?.canonical?.trim() ? normalized.canonical : paperId;
+    try {
+      const overrides = parseJobOverridesDraft();
+      const res = await invoke("dry_run_template", {
+        templateId: selectedTemplateId,
+        canonicalId: idForRun,
+        params: templateParams ?? {},
+        ...overrides,
+      });
+      setDryRunResult(res);
+    } catch (e) {
+      setDryRunResult({ error: e?.message ?? String(e) });
+    }
+  }
+
+  async function onToggleRunManifest(runId) {
+    if (runManifests[runId]) {
+      setRunManifests((prev) => ({ ...prev, [runId]: null }));
+      return;
+    }
+    try {
+      const manifest = await invoke("get_run_manifest", { runId });
+      setRunManifests((prev) => ({ ...prev, [runId]: manifest ?? { missing: true } }));
+    } catch (e) {
+      setRunManifests((prev) => ({ ...prev, [runId]: { error: String(e) } }));
+    }
+  }
+
+  async function onImportReferenceFile() {
+    try {
+      const selected = await openDialog({
+        multiple: false,
+        title: "Select BibTeX / RIS file",
+        filters: [{ name: "References", extensions: ["bib", "bibtex", "ris", "txt"] }],
+      });
+      if (typeof selected !== "string") return;
+      const res = await invoke("import_reference_file", { path: selected, format: null });
+      setReferenceImport(res);
+    } catch (e) {
+      alert(String(e));
     }
   }
 
-  async function onRunTree() {
-    const idForRun = normalized?.canonical?.trim() ? normalized.canonical : paperId;
-    await runTree({
-      templateId: selectedTemplateId,
-      canonicalId: idForRun,
-      templateParams,
-    });
+  async function onEnqueueImportedReferences() {
+    const ids = Array.isArray(referenceImport?.canonical_ids) ? referenceImport.canonical_ids : [];
+    await enqueueIdentifierList(ids);
+  }
+
+  async function onValidatePastedIds() {
+    const inputs = pasteIdsText
+      .split(/\r?\n/)
+      .map((line) => line.trim())
+      .filter((line) => line.length > 0);
+    try {
+      const res = await invoke("normalize_identifiers", { inputs });
+      setPasteIdsValidation(res ?? null);
+    } catch (e) {
+      alert(String(e));
+    }
+  }
+
+  async function enqueueIdentifierList(ids) {
+    if (ids.length === 0) return;
+    if (!window.confirm(`Enqueue ${selectedTemplateId} for ${ids.length} identifiers?`)) return;
+    setReferenceImportBusy(true);
+    const failed = [];
+    try {
+      for (const canonicalId of ids) {
+        try {
+          await invoke("enqueue_job", { templateId: selectedTemplateId, canonicalId, params: templateParams });
+        } catch (e) {
+          failed.push(`${canonicalId}: ${String(e)}`);
+        }
+      }
+      await loadJobs();
+      if (failed.length > 0) {
+        alert(`Failed to enqueue ${failed.length}:\n${failed.join("\n")}`);
+      }
+    } finally {
+      setReferenceImportBusy(false);
+    }
   }
 
   async function onRetry() {
@@ -1993,6 +3730,41 @@ export default function App() {
     }
   }
 
+  async function onDeleteSelectedRun() {
+    if (!selectedRunId) return;
+    if (!window.confirm(`Delete run ${selectedRunId}?`)) return;
+    const toTrash = window.confirm("Move to trash? (Cancel deletes permanently)");
+    try {
+      const res = await invoke("delete_run", { runId: selectedRunId, toTrash });
+      setSelectedRunId("");
+      await Promise.all([loadRuns(), loadJobs(), loadPipelines(), loadLibraryRows()]);
+      alert(
+        `deleted ${res.run_id}: library updated=${res.library_records_updated} dropped=${res.library_records_dropped}` +
+          `, jobs=${res.jobs_detached}, pipeline steps=${res.pipeline_steps_detached}` +
+          (res.trashed_to ? `, trash=${res.trashed_to}` : "")
+      );
+    } catch (e) {
+      alert(String(e));
+    }
+  }
+
+  async function onArchiveSelectedRun() {
+    if (!selectedRunId) return;
+    const dest = await openDialog({ directory: true, multiple: false });
+    if (!dest || Array.isArray(dest)) return;
+    try {
+      const res = await invoke("archive_run", { runId: selectedRunId, dest });
+      setSelectedRunId("");
+      await Promise.all([loadRuns(), loadJobs(), loadPipelines(), loadLibraryRows()]);
+      alert(
+        `archived ${res.run_id} to ${res.archived_to}: library updated=${res.library_records_updated} dropped=${res.library_records_dropped}` +
+          `, jobs=${res.jobs_detached}, pipeline steps=${res.pipeline_steps_detached}`
+      );
+    } catch (e) {
+      alert(String(e));
+    }
+  }
+
   async function onCancelJob(jobId) {
     try {
       await invoke("cancel_job", { jobId });
@@ -2011,12 +3783,140 @@ export default function App() {
     }
   }
 
+  async function onSetJobPriority(jobId, value) {
+    const priority = Number(value);
+    if (!jobId || !Number.isInteger(priority)) return;
+    try {
+      await invoke("set_job_priority", { jobId, priority });
+      await loadJobs();
+    } catch (e) {
+      alert(String(e));
+    }
+  }
+
+  async function onSetJobTimeout(jobId, value) {
+    if (!jobId) return;
+    const raw = String(value).trim();
+    const timeoutSeconds = raw === "" ? null : Number(raw);
+    if (timeoutSeconds !== null && (!Number.isInteger(timeoutSeconds) || timeoutSeconds <= 0)) return;
+    try {
+      await invoke("set_job_timeout", { jobId, timeoutSeconds });
+      await loadJobs();
+    } catch (e) {
+      alert(String(e));
+    }
+  }
+
+  async function onMoveJobToFront(jobId) {
+    if (!jobId) return;
+    try {
+      await invoke("reorder_queue", { jobIds: [jobId] });
+      await loadJobs();
+    } catch (e) {
+      alert(String(e));
+    }
+  }
+
+  async function onRerunPostprocess(jobId) {
+    try {
+      await invoke("rerun_job_postprocess", { jobId });
+      await loadJobs();
+    } catch (e) {
+      alert(String(e));
+    }
+  }
+
   async function onOpenRunFromJob(job) {
     if (!job?.run_id) return;
     await loadRuns();
     setSelectedRunId(job.run_id);
   }
 
+  async function loadConfigProfiles() {
+    try {
+      const res = await invoke("list_profiles");
+      setConfigProfiles(res?.profiles ?? []);
+    } catch (e) {
+      setCfgError(String(e));
+    }
+  }
+
+  async function onSwitchProfile(name) {
+    setCfgLoading(true);
+    setCfgError("");
+    try {
+      const res = await invoke("switch_profile", { name: name || null });
+      setRuntimeCfg(res);
+      await loadConfigProfiles();
+      await loadPreflight();
+      await loadRuns();
+      await loadJobs();
+    } catch (e) {
+      setCfgError(String(e));
+    } finally {
+      setCfgLoading(false);
+    }
+  }
+
+  async function onLoadConfigEditor() {
+    setCfgError("");
+    setConfigEditResult(null);
+    try {
+      const res = await invoke("get_config_file");
+      setConfigEditorBase(res?.config ?? {});
+      setConfigEditorText(JSON.stringify(res?.config ?? {}, null, 2));
+    } catch (e) {
+      setCfgError(String(e));
+    }
+  }
+
+  async function onSubmitConfigEditor(dryRun) {
+    let edited;
+    try {
+      edited = JSON.parse(configEditorText);
+    } catch (e) {
+      setCfgError(`config editor: ${String(e)}`);
+      return;
+    }
+    // Merge patch: keys deleted in the editor are sent as null.
+    const patch = { ...edited };
+    for (const key of Object.keys(configEditorBase ?? {})) {
+      if (!(key in edited)) patch[key] = null;
+    }
+    setCfgLoading(true);
+    setCfgError("");
+    try {
+      const res = await invoke("update_config_file", { patch, dryRun });
+      setConfigEditResult(res);
+      if (res?.applied) {
+        setConfigEditorBase(res.config?.config ?? {});
+        setConfigEditorText(JSON.stringify(res.config?.config ?? {}, null, 2));
+        await loadRuntimeConfig();
+        await loadPreflight();
+      }
+    } catch (e) {
+      setCfgError(String(e));
+    } finally {
+      setCfgLoading(false);
+    }
+  }
+
+  async function onApplyConfigNow() {
+    setCfgLoading(true);
+    setCfgError("");
+    try {
+      const res = await invoke("apply_config_now");
+      setConfigApplyReport(res);
+      setRuntimeCfg(res?.config ?? null);
+      await loadPreflight();
+      await loadJobs();
+    } catch (e) {
+      setCfgError(String(e));
+    } finally {
+      setCfgLoading(false);
+    }
+  }
+
   async function onOpenConfigLocation() {
     try {
       await invoke("open_config_file_location");
@@ -2123,6 +4023,23 @@ export default function App() {
     }
   }
 
+  async function onRelocateOutDir() {
+    setCfgLoading(true);
+    setCfgError("");
+    setRelocateResult(null);
+    try {
+      const res = await invoke("relocate_out_dir", { newPath: outDirDraft });
+      setRelocateResult(res);
+      await loadRuntimeConfig();
+      await loadPreflight();
+      await loadRuns();
+    } catch (e) {
+      setCfgError(String(e));
+    } finally {
+      setCfgLoading(false);
+    }
+  }
+
   async function onClearOutDirOverride() {
     setCfgLoading(true);
     setCfgError("");
@@ -2169,6 +4086,7 @@ export default function App() {
       return {
         key,
         label: String(spec.title ?? key),
+        description: spec.description ? String(spec.description) : "",
         fieldType,
         enumValues,
         minimum: Number.isFinite(spec.minimum) ? spec.minimum : undefined,
@@ -2179,6 +4097,7 @@ export default function App() {
   const templateLegacyFieldEntries = (selectedTemplate?.params ?? []).map((p) => ({
     key: p.key,
     label: p.label,
+    description: p.description ?? "",
     fieldType: p.param_type === "integer" ? "integer" : "string",
     enumValues: null,
     minimum: p.min ?? undefined,
@@ -2336,10 +4255,15 @@ export default function App() {
       ? `${runStatsAvgDuration.toFixed(1)}s`
       : "-";
   const isLibrarySearchMode = String(librarySearchQuery ?? "").trim() !== "";
-  const visibleLibraryRows = isLibrarySearchMode ? librarySearchRows : libraryRows;
+  const visibleLibraryRows = activeSavedSearch
+    ? savedSearchRows
+    : isLibrarySearchMode
+      ? librarySearchRows
+      : libraryRows;
   const artifactKind = artifactView?.kind ?? "";
   const isHtmlArtifact = artifactKind === "html";
   const isGraphJsonArtifact = artifactKind === "graph_json";
+  const isImageArtifact = artifactKind === "image" || artifactKind === "svg";
   const graphNodes = Array.isArray(graphParsed?.nodes) ? graphParsed.nodes : [];
   const graphEdges = Array.isArray(graphParsed?.edges) ? graphParsed.edges : [];
   const graphTypes = useMemo(() => {
@@ -2435,7 +4359,8 @@ export default function App() {
       ? (catalog?.items ?? []).find((i) => i.name === preferredViz.name)
       : null;
     const fallbackViewTarget = (catalog?.items ?? []).find((i) => i.kind === "html")
-      || (catalog?.items ?? []).find((i) => i.kind === "graph_json");
+      || (catalog?.items ?? []).find((i) => i.kind === "graph_json")
+      || (catalog?.items ?? []).find((i) => i.name === "summary.md");
     const viewTarget = primaryViewTarget || fallbackViewTarget;
 
     const makeDisabled = (name) => {
@@ -2465,6 +4390,18 @@ export default function App() {
         >
           Tree
         </button>
+        {hasName("summary.md") ? (
+          <button
+            title="Open summary.md"
+            onClick={(e) => {
+              e.stopPropagation();
+              onOpenArtifactQuick(runIdFromRow, "summary.md");
+            }}
+            style={{ padding: "4px 8px", borderRadius: 6, border: "1px solid #333", fontSize: 11 }}
+          >
+            Summary
+          </button>
+        ) : null}
         <button
           title={makeTitle("result.json")}
           onClick={(e) => {
@@ -2528,6 +4465,36 @@ export default function App() {
   return (
     <div style={{ fontFamily: "system-ui", padding: 16, maxWidth: 960 }}>
       <h2 style={{ marginTop: 0 }}>Javis Desktop</h2>
+      {shutdownStatus && shutdownStatus.phase !== "idle" ? (
+        <div style={{ padding: 8, marginBottom: 8, borderRadius: 8, background: "#fff4e5", border: "1px solid #e0a040" }}>
+          Shutting down: stopping {shutdownStatus.running_job_ids?.length ?? 0} running job(s).{" "}
+          {shutdownStatus.interrupted_job_ids?.length ?? 0} job(s) will be retried on next start.
+        </div>
+      ) : null}
+      {quarantinedFiles.length > 0 ? (
+        <details style={{ fontSize: 12, marginBottom: 8, color: "#a33" }}>
+          <summary>
+            {quarantinedFiles.length} damaged state file(s) were set aside in .jarvis-desktop/corrupt/
+          </summary>
+          {quarantinedFiles.map((file) => (
+            <div key={file.name}>
+              {file.original}: {new Date(file.quarantined_at_ms).toLocaleString()} ({file.bytes} bytes){" "}
+              <code>{file.path}</code>
+            </div>
+          ))}
+        </details>
+      ) : null}
+      {startupIssues.length > 0 ? (
+        <details style={{ fontSize: 12, marginBottom: 8, color: "#8a4200" }}>
+          <summary>startup cleanup: {startupIssues.length} item(s)</summary>
+          {startupIssues.map((issue, idx) => (
+            <div key={`${issue.code}-${idx}`}>
+              [{issue.code}] {issue.message}
+              {issue.context ? ` (${issue.context})` : ""}
+            </div>
+          ))}
+        </details>
+      ) : null}
 
       <div style={{ display: "flex", gap: 8, marginBottom: 12 }}>
         <button
@@ -2697,6 +4664,75 @@ export default function App() {
         <div style={{ fontSize: 12, opacity: 0.9 }}>
           out_dir: <code>{runtimeCfg?.out_dir ?? "-"}</code>
         </div>
+        <div style={{ marginTop: 6, display: "flex", gap: 8, alignItems: "center", fontSize: 12 }}>
+          profile:
+          <select
+            value={runtimeCfg?.active_profile ?? ""}
+            onFocus={loadConfigProfiles}
+            onChange={(e) => onSwitchProfile(e.target.value)}
+            disabled={cfgLoading}
+          >
+            <option value="">(none)</option>
+            {runtimeCfg?.active_profile && !configProfiles.some((p) => p.name === runtimeCfg.active_profile) ? (
+              <option value={runtimeCfg.active_profile}>{runtimeCfg.active_profile}</option>
+            ) : null}
+            {configProfiles.map((p) => (
+              <option key={p.name} value={p.name} disabled={!!p.error}>
+                {p.name}
+                {p.error ? " (invalid)" : ""}
+              </option>
+            ))}
+          </select>
+        </div>
+        {configApplyReport ? (
+          <div style={{ marginTop: 6, fontSize: 12 }}>
+            config generation {configApplyReport.generation}
+            {(configApplyReport.subsystems ?? []).map((s) => (
+              <div key={s.name}>
+                {s.name}: {s.reinitialized ? "re-initialized" : "unchanged"} ({s.detail})
+              </div>
+            ))}
+          </div>
+        ) : null}
+        <details style={{ marginTop: 10, fontSize: 12 }} onToggle={(e) => e.target.open && onLoadConfigEditor()}>
+          <summary style={{ fontWeight: 600 }}>Edit config.json</summary>
+          <textarea
+            value={configEditorText}
+            onChange={(e) => setConfigEditorText(e.target.value)}
+            rows={10}
+            spellCheck={false}
+            style={{ marginTop: 6, width: "100%", fontFamily: "monospace", fontSize: 12 }}
+          />
+          <div style={{ marginTop: 6, display: "flex", gap: 8 }}>
+            <button onClick={() => onSubmitConfigEditor(true)} disabled={cfgLoading}>
+              Preview
+            </button>
+            <button onClick={() => onSubmitConfigEditor(false)} disabled={cfgLoading}>
+              Save
+            </button>
+            <button onClick={onLoadConfigEditor} disabled={cfgLoading}>
+              Reload
+            </button>
+          </div>
+          {configEditResult ? (
+            <div style={{ marginTop: 6 }}>
+              <div>
+                {configEditResult.applied ? "saved" : "not saved"}; changed:{" "}
+                {configEditResult.changed_keys.join(", ") || "(none)"}
+              </div>
+              {configEditResult.errors.map((err) => (
+                <div key={err} style={{ color: "#b00020" }}>
+                  {err}
+                </div>
+              ))}
+              {(configEditResult.preflight?.checks ?? []).map((c) => (
+                <div key={c.name} style={{ color: c.ok ? "inherit" : "#b00020" }}>
+                  {c.ok ? "OK" : "NG"} {c.name}: {c.detail}
+                </div>
+              ))}
+            </div>
+          ) : null}
+        </details>
         <div style={{ marginTop: 10, fontSize: 12, fontWeight: 600 }}>
           Pipeline root override (config.json)
         </div>
@@ -2753,6 +4789,14 @@ export default function App() {
           >
             Apply
           </button>
+          <button
+            onClick={onRelocateOutDir}
+            disabled={cfgLoading}
+            title="Use after moving the whole out_dir folder; rewrites stored paths"
+            style={{ padding: "8px 12px", borderRadius: 8, border: "1px solid #333" }}
+          >
+            Relocate
+          </button>
           <button
             onClick={onClearOutDirOverride}
             disabled={cfgLoading}
@@ -2761,6 +4805,17 @@ export default function App() {
             Clear
           </button>
         </div>
+        {relocateResult && (
+          <div style={{ fontSize: 12, marginTop: 4 }}>
+            Relocated to {relocateResult.new_out_dir}: rewrote {relocateResult.rewritten?.length ?? 0} file(s)
+            {(relocateResult.warnings ?? []).map((w, i) => (
+              <div key={i} style={{ color: "#a33" }}>
+                [{w.code}] {w.message}
+                {w.context ? ` (${w.context})` : ""}
+              </div>
+            ))}
+          </div>
+        )}
         <div style={{ fontSize: 12, marginTop: 4 }}>
           Config validation:{" "}
           <strong style={{ color: runtimeCfg?.ok ? "#1f6f3f" : "#a33" }}>
@@ -2791,6 +4846,13 @@ export default function App() {
           >
             {cfgLoading ? "Reloading..." : "Reload config"}
           </button>
+          <button
+            onClick={onApplyConfigNow}
+            disabled={cfgLoading}
+            style={{ padding: "8px 12px", borderRadius: 8, border: "1px solid #333" }}
+          >
+            Apply config now
+          </button>
           <button
             onClick={loadPreflight}
             disabled={preflightLoading}
@@ -2798,7 +4860,21 @@ export default function App() {
           >
             {preflightLoading ? "Preflight..." : "Run preflight"}
           </button>
+          <button
+            onClick={onCheckS2Key}
+            disabled={s2KeyCheck?.status === "checking"}
+            style={{ padding: "8px 12px", borderRadius: 8, border: "1px solid #333" }}
+          >
+            Check S2 key
+          </button>
         </div>
+        {s2KeyCheck && s2KeyCheck.status !== "checking" ? (
+          <div style={{ marginTop: 8, fontSize: 12, color: s2KeyCheck.ok ? "#1f6f3f" : "#a33" }}>
+            S2 key: <code>{s2KeyCheck.status}</code> {s2KeyCheck.message}
+            {s2KeyCheck.rate_limit_remaining != null ? ` (remaining ${s2KeyCheck.rate_limit_remaining})` : ""}
+            {s2KeyCheck.retry_after_seconds != null ? ` (retry after ${s2KeyCheck.retry_after_seconds}s)` : ""}
+          </div>
+        ) : null}
         {cfgError ? (
           <div style={{ marginTop: 8, color: "#a33", fontSize: 12 }}>
             config status=missing_dependency: {cfgError}
@@ -2827,6 +4903,7 @@ export default function App() {
             {templates.map((t) => (
               <option key={t.id} value={t.id}>
                 {t.title} ({t.id}){t.wired ? "" : " - not wired"}
+                {t.source ? " - custom" : ""}
               </option>
             ))}
           </select>
@@ -2834,6 +4911,11 @@ export default function App() {
       </div>
 
       {templatesError ? <div style={{ marginTop: 8, color: "#a33", fontSize: 12 }}>{templatesError}</div> : null}
+      {templateRegistryErrors.map((err) => (
+        <div key={err} style={{ marginTop: 4, color: "#a33", fontSize: 12 }}>
+          templates.json: {err}
+        </div>
+      ))}
 
       <div
         style={{
@@ -2850,7 +4932,7 @@ export default function App() {
             <>
               <strong>{selectedTemplate.title}</strong>: {selectedTemplate.description}
               {!selectedTemplate.wired ? (
-                <span style={{ marginLeft: 8, color: "#a33" }}>not wired</span>
+                <span style={{ marginLeft: 8, color: "#a33" }}>{selectedTemplate.disabled_reason || "not wired"}</span>
               ) : null}
             </>
           ) : (
@@ -2864,7 +4946,7 @@ export default function App() {
         ) : null}
         <div style={{ display: "grid", gap: 8, gridTemplateColumns: "repeat(auto-fit, minmax(200px, 1fr))" }}>
           {templateDynamicFields.map((field) => (
-            <label key={field.key} style={{ display: "grid", gap: 4 }}>
+            <label key={field.key} style={{ display: "grid", gap: 4 }} title={field.description || undefined}>
               <span style={{ fontSize: 12 }}>
                 {field.label}
                 {templateRequiredFieldSet.has(field.key) ? " *" : ""}
@@ -2917,6 +4999,26 @@ export default function App() {
                   style={{ padding: 8, borderRadius: 6, border: "1px solid #ccc" }}
                 />
               )}
+              {(() => {
+                const check = (templateParamCheck?.fields ?? []).find((f) => f.key === field.key);
+                if (!check?.error) return null;
+                return (
+                  <span style={{ fontSize: 11, color: "#a33" }}>
+                    {check.error}
+                    {check.clamped != null ? (
+                      <button
+                        onClick={(e) => {
+                          e.preventDefault();
+                          updateTemplateParams({ ...templateParams, [field.key]: check.clamped });
+                        }}
+                        style={{ marginLeft: 6, fontSize: 11 }}
+                      >
+                        use {check.clamped}
+                      </button>
+                    ) : null}
+                  </span>
+                );
+              })()}
               {templateRequiredFieldSet.has(field.key) && missingTemplateRequiredFieldSet.has(field.key) ? (
                 <span style={{ fontSize: 11, color: "#a33" }}>required</span>
               ) : null}
@@ -2955,6 +5057,41 @@ export default function App() {
             </div>
           </div>
         </details>
+        <details style={{ marginTop: 8 }}>
+          <summary style={{ cursor: "pointer", fontSize: 12 }}>Advanced: per-job env / extra args</summary>
+          <div style={{ marginTop: 6, display: "grid", gap: 6, fontSize: 12 }}>
+            <textarea
+              value={jobEnvOverridesDraft}
+              onChange={(e) => setJobEnvOverridesDraft(e.target.value)}
+              placeholder={"S2_MIN_INTERVAL_MS=1500\nS2_API_KEY=..."}
+              style={{ width: "100%", minHeight: 60, padding: 8, borderRadius: 6, border: "1px solid #ccc", fontFamily: "ui-monospace, SFMono-Regular, Menlo, Consolas, monospace", fontSize: 12 }}
+            />
+            <input
+              value={jobExtraArgsDraft}
+              onChange={(e) => setJobExtraArgsDraft(e.target.value)}
+              placeholder="--verbose --log-level debug"
+              style={{ padding: 6, borderRadius: 6, border: "1px solid #ccc", fontSize: 12 }}
+            />
+          </div>
+        </details>
+        <div style={{ marginTop: 8, fontSize: 12 }}>
+          <button
+            onClick={onDryRunTemplate}
+            disabled={!selectedTemplateId}
+            style={{ padding: "6px 10px", borderRadius: 6, border: "1px solid #333", fontSize: 11 }}
+          >
+            Dry run (show command)
+          </button>
+          {dryRunResult?.error ? <div style={{ color: "#a33" }}>{dryRunResult.error}</div> : null}
+          {dryRunResult?.command_line ? (
+            <pre style={{ margin: "6px 0 0", whiteSpace: "pre-wrap", wordBreak: "break-all", fontSize: 11, border: "1px solid #ddd", borderRadius: 6, padding: 8, background: "#fff" }}>
+              {`cd ${dryRunResult.working_dir}\n`}
+              {Object.entries(dryRunResult.env ?? {}).map(([k, v]) => `${k}=${v}\n`).join("")}
+              {dryRunResult.command_line}
+              {(dryRunResult.warnings ?? []).map((w) => `\n# warning: ${w}`).join("")}
+            </pre>
+          ) : null}
+        </div>
         <div style={{ marginTop: 8 }}>
           <div style={{ fontSize: 12, fontWeight: 600, marginBottom: 4 }}>Generated input preview</div>
           <pre style={{ margin: 0, maxHeight: 180, overflow: "auto", whiteSpace: "pre-wrap", wordBreak: "break-word", fontSize: 11, border: "1px solid #ddd", borderRadius: 6, padding: 8, background: "#fff" }}>
@@ -2985,6 +5122,34 @@ export default function App() {
             errors: {normalizeErrors.join(" | ")}
           </div>
         ) : null}
+        {normalizeErrors.length > 0 && String(paperId ?? "").trim() ? (
+          <div style={{ marginTop: 6 }}>
+            <button type="button" onClick={resolveIdentifierQuery} disabled={identifierResolving}>
+              {identifierResolving ? "Searching..." : "Search Crossref / OpenAlex / PubMed"}
+            </button>
+          </div>
+        ) : null}
+        {identifierResolution ? (
+          <div style={{ marginTop: 6, fontSize: 12 }}>
+            {(identifierResolution.candidates ?? []).map((c) => (
+              <div key={c.canonical} style={{ display: "flex", gap: 6, alignItems: "center", marginTop: 4 }}>
+                <button type="button" onClick={() => applyResolvedCandidate(c)}>
+                  Use
+                </button>
+                <code>{c.canonical}</code>
+                <span>
+                  {c.title} {c.year ? `(${c.year})` : ""} - {Math.round(c.confidence * 100)}% [{c.sources.join(", ")}]
+                </span>
+              </div>
+            ))}
+            {(identifierResolution.candidates ?? []).length === 0 && (identifierResolution.errors ?? []).length === 0 ? (
+              <div>No matches.</div>
+            ) : null}
+            {(identifierResolution.errors ?? []).length > 0 ? (
+              <div style={{ color: "#8a4200" }}>{identifierResolution.errors.join(" | ")}</div>
+            ) : null}
+          </div>
+        ) : null}
       </div>
 
       <div
@@ -3128,7 +5293,117 @@ export default function App() {
           {runId ? <span style={{ marginLeft: 10 }}>run_id={runId}</span> : null}
           {status ? <span style={{ marginLeft: 10 }}>status={status}</span> : null}
         </div>
-      </div>
+      </div>
+
+      <div style={{ display: "flex", gap: 8, marginTop: 8, alignItems: "center", flexWrap: "wrap" }}>
+        <button
+          onClick={onImportReferenceFile}
+          style={{ padding: "6px 10px", borderRadius: 8, border: "1px solid #333" }}
+        >
+          Import .bib / .ris...
+        </button>
+        <button
+          onClick={() => setPasteIdsOpen((open) => !open)}
+          style={{ padding: "6px 10px", borderRadius: 8, border: "1px solid #333" }}
+        >
+          Paste IDs...
+        </button>
+        {referenceImport ? (
+          <>
+            <span style={{ fontSize: 12, opacity: 0.85 }}>
+              {referenceImport.format}: {referenceImport.entries?.length ?? 0} entries,{" "}
+              {referenceImport.canonical_ids?.length ?? 0} identifiers
+            </span>
+            <button
+              onClick={onEnqueueImportedReferences}
+              disabled={referenceImportBusy || (referenceImport.canonical_ids?.length ?? 0) === 0}
+              style={{ padding: "6px 10px", borderRadius: 8, border: "1px solid #333" }}
+            >
+              {referenceImportBusy ? "Enqueueing..." : `Enqueue ${selectedTemplateId} for all`}
+            </button>
+            <button
+              onClick={() => setReferenceImport(null)}
+              style={{ padding: "6px 10px", borderRadius: 8, border: "1px solid #333" }}
+            >
+              Clear
+            </button>
+          </>
+        ) : null}
+      </div>
+      {pasteIdsOpen ? (
+        <div style={{ marginTop: 6, fontSize: 12 }}>
+          <textarea
+            value={pasteIdsText}
+            onChange={(e) => {
+              setPasteIdsText(e.target.value);
+              setPasteIdsValidation(null);
+            }}
+            placeholder="One identifier per line (DOI, PMID, arXiv, PMCID, ...)"
+            rows={6}
+            style={{ width: "100%", fontFamily: "monospace", fontSize: 12 }}
+          />
+          <div style={{ display: "flex", gap: 8, marginTop: 4, alignItems: "center" }}>
+            <button onClick={onValidatePastedIds} disabled={!pasteIdsText.trim()}>
+              Validate
+            </button>
+            {pasteIdsValidation ? (
+              <>
+                <span>
+                  {pasteIdsValidation.valid_count} valid, {pasteIdsValidation.invalid_count} invalid,{" "}
+                  {pasteIdsValidation.duplicates.length} duplicated
+                </span>
+                <button
+                  onClick={() => enqueueIdentifierList(pasteIdsValidation.canonical_ids)}
+                  disabled={referenceImportBusy || pasteIdsValidation.canonical_ids.length === 0}
+                >
+                  {referenceImportBusy
+                    ? "Enqueueing..."
+                    : `Enqueue ${selectedTemplateId} for ${pasteIdsValidation.canonical_ids.length} unique`}
+                </button>
+              </>
+            ) : null}
+          </div>
+          {pasteIdsValidation ? (
+            <table style={{ marginTop: 6, borderCollapse: "collapse" }}>
+              <tbody>
+                {pasteIdsValidation.rows.map((row) => (
+                  <tr key={row.index}>
+                    <td style={{ paddingRight: 8 }}>{row.input}</td>
+                    <td style={{ paddingRight: 8 }}>
+                      <code>{row.normalized.kind}</code>
+                    </td>
+                    <td style={{ paddingRight: 8 }}>
+                      <code>{row.pipeline_id ?? "-"}</code>
+                    </td>
+                    <td style={{ color: row.pipeline_error ? "#a33" : "#8a5a00" }}>
+                      {row.pipeline_error ??
+                        (row.duplicate_of != null ? `duplicate of line ${row.duplicate_of + 1}` : "")}
+                    </td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          ) : null}
+        </div>
+      ) : null}
+      {referenceImport ? (
+        <details style={{ marginTop: 6, fontSize: 12 }}>
+          <summary>Imported entries</summary>
+          {(referenceImport.warnings ?? []).map((w, i) => (
+            <div key={`file-warn-${i}`} style={{ color: "#8a5a00" }}>
+              {w}
+            </div>
+          ))}
+          {(referenceImport.entries ?? []).map((entry) => (
+            <div key={entry.index} style={{ marginTop: 4 }}>
+              <code>{entry.canonical_id ?? "-"}</code> {entry.title ?? entry.key ?? ""}
+              {entry.warnings?.length ? (
+                <span style={{ color: "#8a5a00" }}> ({entry.warnings.join("; ")})</span>
+              ) : null}
+            </div>
+          ))}
+        </details>
+      ) : null}
 
       {runDir ? (
         <div style={{ marginTop: 8, opacity: 0.85, fontSize: 12 }}>
@@ -3194,8 +5469,42 @@ export default function App() {
         >
           {jobsLoading ? "Refreshing..." : "Refresh jobs"}
         </button>
+        <button
+          onClick={() => loadFailureClusters(30)}
+          style={{ padding: "8px 12px", borderRadius: 8, border: "1px solid #333" }}
+        >
+          Failure clusters (30d)
+        </button>
+        <button
+          onClick={onToggleQueuePaused}
+          disabled={!desktopSettings}
+          title="Running jobs finish; queued jobs wait until resumed"
+          style={{ padding: "8px 12px", borderRadius: 8, border: "1px solid #333" }}
+        >
+          {desktopSettings?.queue?.paused ? "Resume queue" : "Pause queue"}
+        </button>
       </div>
+      {desktopSettings?.queue?.paused ? (
+        <div style={{ color: "#8a4200", fontSize: 12, marginBottom: 8 }}>
+          Queue paused: queued jobs will not start until the queue is resumed.
+        </div>
+      ) : null}
       {jobsError ? <div style={{ color: "#a33", fontSize: 12 }}>{jobsError}</div> : null}
+      {failureClusters ? (
+        <div style={{ border: "1px solid #eee", borderRadius: 6, padding: 8, marginBottom: 8, fontSize: 12 }}>
+          <div style={{ fontWeight: 600, marginBottom: 4 }}>
+            Failure clusters: {failureClusters.total_failures} failure(s), {(failureClusters.clusters ?? []).length} signature(s)
+          </div>
+          {(failureClusters.clusters ?? []).slice(0, 10).map((c) => (
+            <div key={c.signature} style={{ marginBottom: 4 }} title={c.sample_error}>
+              <strong>{Math.round((c.share ?? 0) * 100)}%</strong> ({c.count}) <code>{c.signature}</code>
+              <div style={{ opacity: 0.75 }}>
+                templates={c.templates.join(", ")} first={c.first_seen} last={c.last_seen}
+              </div>
+            </div>
+          ))}
+        </div>
+      ) : null}
       <div style={{ display: "grid", gridTemplateColumns: "1fr 1.8fr", gap: 12, marginBottom: 12 }}>
         <div style={{ border: "1px solid #ddd", borderRadius: 8, maxHeight: 240, overflow: "auto" }}>
           {jobs.length === 0 ? (
@@ -3216,7 +5525,17 @@ export default function App() {
                 }}
               >
                 <div style={{ fontSize: 12, fontWeight: 600 }}>{j.job_id}</div>
-                <div style={{ fontSize: 11 }}>status={j.status} attempt={j.attempt}</div>
+                <div style={{ fontSize: 11 }}>
+                  status={j.status} attempt={j.attempt}
+                  {jobWindowWaits[j.job_id] ? ` (${jobWindowWaits[j.job_id].reason})` : ""}
+                  {j.timing?.duration_ms != null && j.status !== "running" && j.status !== "queued"
+                    ? ` took ${Math.round(j.timing.duration_ms / 1000)}s`
+                    : ""}
+                  {jobQueueEtas[j.job_id] ? ` ${queuePositionLabel(jobQueueEtas[j.job_id])}` : ""}
+                  {j.timing?.estimated_remaining_ms != null && j.status === "running"
+                    ? ` ~${Math.max(1, Math.round(j.timing.estimated_remaining_ms / 60000))} min left`
+                    : ""}
+                </div>
                 <div style={{ fontSize: 11, opacity: 0.75 }}>{j.template_id} / {j.canonical_id}</div>
               </button>
             ))
@@ -3231,8 +5550,75 @@ export default function App() {
               retry_in_sec: <code>{Math.max(0, Math.floor((Number(selectedJob.retry_at) - Date.now()) / 1000))}</code>
             </div>
           ) : null}
+          {selectedJob && jobWindowWaits[selectedJob.job_id] ? (
+            <div style={{ fontSize: 12, marginBottom: 4 }}>
+              {jobWindowWaits[selectedJob.job_id].reason}: window{" "}
+              <code>
+                {jobWindowWaits[selectedJob.job_id].window_start}-{jobWindowWaits[selectedJob.job_id].window_end}
+              </code>{" "}
+              opens in{" "}
+              <code>
+                {formatWindowCountdown(
+                  jobWindowWaits[selectedJob.job_id].opens_in_seconds -
+                    Math.floor((Date.now() - jobWindowWaits[selectedJob.job_id].loadedAt) / 1000)
+                )}
+              </code>
+            </div>
+          ) : null}
           {selectedJob?.last_error ? (
-            <div style={{ fontSize: 12, color: "#a33", marginBottom: 6 }}>error: {selectedJob.last_error}</div>
+            <div style={{ fontSize: 12, color: "#a33", marginBottom: 6 }}>
+              error{selectedJob?.failure_reason ? ` (${selectedJob.failure_reason})` : ""}: {selectedJob.last_error}
+            </div>
+          ) : null}
+          {selectedJob && (jobLiveOutput[selectedJob.job_id] ?? []).length > 0 ? (
+            <pre style={{ fontSize: 11, maxHeight: 160, overflow: "auto", background: "#f7f7f7", padding: 6, marginBottom: 6 }}>
+              {jobLiveOutput[selectedJob.job_id].join("\n")}
+            </pre>
+          ) : null}
+          {selectedJob?.deleted_run ? (
+            <div style={{ fontSize: 12, opacity: 0.8, marginBottom: 6 }}>
+              run <code>{selectedJob.deleted_run.run_id}</code> deleted at {selectedJob.deleted_run.deleted_at}
+              {selectedJob.deleted_run.trashed_to ? (
+                <>
+                  {" "}
+                  (trash: <code>{selectedJob.deleted_run.trashed_to}</code>)
+                </>
+              ) : null}
+              {selectedJob.deleted_run.archived_to ? (
+                <>
+                  {" "}
+                  (archived: <code>{selectedJob.deleted_run.archived_to}</code>)
+                </>
+              ) : null}
+            </div>
+          ) : null}
+          {selectedJob?.postprocess ? (
+            <div style={{ fontSize: 12, marginBottom: 6 }}>
+              postprocess: <code>{selectedJob.postprocess.status}</code>
+              {(selectedJob.postprocess.steps ?? []).map((step, idx) => (
+                <div
+                  key={`${step.script}-${idx}`}
+                  style={{ marginLeft: 12, color: step.status === "failed" ? "#a33" : undefined }}
+                >
+                  {step.script}: {step.status}
+                  {step.exit_code != null ? ` (exit ${step.exit_code})` : ""}
+                  {step.message ? ` - ${step.message}` : ""}
+                  {step.log_path ? (
+                    <>
+                      {" "}
+                      log=<code>{step.log_path}</code>
+                    </>
+                  ) : null}
+                </div>
+              ))}
+              <button
+                onClick={() => onRerunPostprocess(selectedJob.job_id)}
+                disabled={selectedJob.status !== "succeeded" || selectedJob.postprocess.status === "running"}
+                style={{ marginTop: 4 }}
+              >
+                Rerun postprocess
+              </button>
+            </div>
           ) : null}
           <div style={{ display: "flex", gap: 8 }}>
             <button
@@ -3256,6 +5642,47 @@ export default function App() {
             >
               Retry(force)
             </button>
+            <label style={{ display: "flex", alignItems: "center", gap: 4, fontSize: 12 }}>
+              priority
+              <input
+                type="number"
+                min={-100}
+                max={100}
+                key={`${selectedJob?.job_id}-${selectedJob?.priority ?? 0}`}
+                defaultValue={selectedJob?.priority ?? 0}
+                disabled={!selectedJob || selectedJob.status !== "queued"}
+                onBlur={(e) => {
+                  if (Number(e.target.value) !== (selectedJob?.priority ?? 0)) {
+                    onSetJobPriority(selectedJob?.job_id, e.target.value);
+                  }
+                }}
+                style={{ width: 56 }}
+              />
+            </label>
+            <label style={{ display: "flex", alignItems: "center", gap: 4, fontSize: 12 }}>
+              timeout(s)
+              <input
+                type="number"
+                min={1}
+                placeholder="template"
+                key={`${selectedJob?.job_id}-timeout-${selectedJob?.timeout_seconds ?? ""}`}
+                defaultValue={selectedJob?.timeout_seconds ?? ""}
+                disabled={!selectedJob || selectedJob.status === "running"}
+                onBlur={(e) => {
+                  if (e.target.value !== String(selectedJob?.timeout_seconds ?? "")) {
+                    onSetJobTimeout(selectedJob?.job_id, e.target.value);
+                  }
+                }}
+                style={{ width: 72 }}
+              />
+            </label>
+            <button
+              onClick={() => onMoveJobToFront(selectedJob?.job_id)}
+              disabled={!selectedJob || selectedJob.status !== "queued"}
+              style={{ padding: "8px 12px", borderRadius: 8, border: "1px solid #333" }}
+            >
+              Move to front
+            </button>
             <button
               onClick={() => onOpenRunFromJob(selectedJob)}
               disabled={!selectedJob?.run_id}
@@ -3269,6 +5696,11 @@ export default function App() {
 
       <hr style={{ margin: "18px 0" }} />
       <h3 style={{ marginBottom: 8 }}>Pipelines</h3>
+      {pipelineTemplateWarnings.length > 0 && (
+        <div style={{ color: "#8a5a00", fontSize: 12, marginBottom: 6 }}>
+          {pipelineTemplateWarnings.map((w) => `${w.code}: ${w.message}`).join(" / ")}
+        </div>
+      )}
       <div style={{ display: "flex", gap: 8, marginBottom: 8, flexWrap: "wrap" }}>
         <button
           onClick={onRunAnalyzePipeline}
@@ -3277,6 +5709,28 @@ export default function App() {
         >
           Run Pipeline: Analyze Paper
         </button>
+        <select
+          value={selectedPipelineTemplateId}
+          onFocus={loadPipelineTemplates}
+          onChange={(e) => setSelectedPipelineTemplateId(e.target.value)}
+          title={pipelineTemplates.find((t) => t.id === selectedPipelineTemplateId)?.description ?? ""}
+          style={{ padding: "8px", borderRadius: 8, border: "1px solid #333" }}
+        >
+          {pipelineTemplates.length === 0 && <option value="full_analysis">Full Analysis</option>}
+          {pipelineTemplates.map((t) => (
+            <option key={t.id} value={t.id}>
+              {t.name}
+              {t.builtin ? "" : " (custom)"}
+            </option>
+          ))}
+        </select>
+        <button
+          onClick={onRunPipelineTemplate}
+          disabled={pipelineStartDisabled}
+          style={{ padding: "8px 12px", borderRadius: 8, border: "1px solid #333" }}
+        >
+          Run template
+        </button>
         <button
           onClick={onFixRuntimeAfterImport}
           disabled={workspaceFixingRuntime}
@@ -3353,7 +5807,11 @@ export default function App() {
                 }}
               >
                 <div style={{ fontSize: 12, fontWeight: 600 }}>{p.name}</div>
-                <div style={{ fontSize: 11, opacity: 0.85 }}>status={p.status} step={Math.min((p.current_step_index ?? 0) + 1, p.total_steps ?? 0)}/{p.total_steps}</div>
+                <div style={{ fontSize: 11, opacity: 0.85 }}>
+                  status={p.status} step={Math.min((p.current_step_index ?? 0) + 1, p.total_steps ?? 0)}/{p.total_steps}
+                  {pipelineEtaText(p) ? ` eta=${pipelineEtaText(p)}` : ""}
+                  {p.skipped_steps ? ` skipped=${p.skipped_steps}` : ""}
+                </div>
                 <div style={{ fontSize: 11, opacity: 0.75 }}>{p.canonical_id}</div>
                 <div style={{ fontSize: 11, opacity: 0.75 }}>updated_at={p.updated_at}</div>
               </button>
@@ -3416,6 +5874,20 @@ export default function App() {
                   <div style={{ fontSize: 12, fontWeight: 600 }}>{s.step_id} ({s.template_id})</div>
                   <div style={{ fontSize: 11 }}>status=<code>{s.status}</code> job_id=<code>{s.job_id ?? "-"}</code></div>
                   <div style={{ fontSize: 11, opacity: 0.8 }}>started_at={s.started_at ?? "-"} finished_at={s.finished_at ?? "-"}</div>
+                  {s.condition ? (
+                    <div style={{ fontSize: 11, opacity: 0.8 }}>
+                      condition: <code>{s.condition.step ?? "previous step"} {s.condition.metric} {s.condition.op} {JSON.stringify(s.condition.value)}</code>
+                    </div>
+                  ) : null}
+                  {s.skip_reason ? <div style={{ fontSize: 11, color: "#666" }}>skipped: {s.skip_reason}</div> : null}
+                  {s.last_error ? <div style={{ fontSize: 11, color: "#a33" }}>error: {s.last_error}</div> : null}
+                  {s.max_retries != null || s.timeout_seconds != null ? (
+                    <div style={{ fontSize: 11, opacity: 0.8 }}>
+                      retries={s.retry_count ?? 0}/{s.max_retries ?? "-"} backoff={s.retry_backoff_seconds ?? 0}s timeout=
+                      {s.timeout_seconds != null ? `${s.timeout_seconds}s` : "template"}
+                      {s.retry_at ? ` next_retry_at=${s.retry_at}` : ""}
+                    </div>
+                  ) : null}
                   <div style={{ marginTop: 6, display: "flex", gap: 6, flexWrap: "wrap" }}>
                     <button
                       onClick={() => onOpenRunFromLibrary(s.run_id)}
@@ -3449,6 +5921,29 @@ export default function App() {
       <hr style={{ margin: "18px 0" }} />
 
       <h3 style={{ marginBottom: 8 }}>Library</h3>
+      {Array.isArray(artifactSearchHits) ? (
+        <div style={{ border: "1px solid #ddd", borderRadius: 8, padding: 8, marginBottom: 8, fontSize: 12 }}>
+          <div style={{ display: "flex", justifyContent: "space-between", marginBottom: 4 }}>
+            <strong>Artifact hits: {artifactSearchHits.length}</strong>
+            <button onClick={() => setArtifactSearchHits(null)} style={{ fontSize: 11 }}>
+              Close
+            </button>
+          </div>
+          {artifactSearchHits.map((hit) => (
+            <div key={hit.run_id} style={{ marginTop: 6 }}>
+              <button onClick={() => onOpenRunFromLibrary(hit.run_id)} style={{ fontSize: 11 }}>
+                {hit.run_id}
+              </button>{" "}
+              {hit.title ?? hit.canonical_id ?? ""} <span style={{ opacity: 0.7 }}>score={hit.score}</span>
+              {(hit.files ?? []).map((f) => (
+                <div key={f.rel_path} style={{ marginLeft: 12, opacity: 0.85 }}>
+                  <code>{f.rel_path}</code> {f.snippet ? `... ${f.snippet} ...` : ""}
+                </div>
+              ))}
+            </div>
+          ))}
+        </div>
+      ) : null}
       <div style={{ display: "flex", gap: 8, marginBottom: 8, flexWrap: "wrap" }}>
         <input
           placeholder="Search library (canonical/title/tag/template/run/status)"
@@ -3456,6 +5951,31 @@ export default function App() {
           onChange={(e) => setLibrarySearchQuery(e.target.value)}
           style={{ padding: 8, borderRadius: 6, border: "1px solid #ccc", minWidth: 320 }}
         />
+        <label style={{ display: "flex", alignItems: "center", gap: 6, fontSize: 12 }}>
+          <input
+            type="checkbox"
+            checked={desktopSettings?.ui?.search_transliteration !== false}
+            disabled={!desktopSettings}
+            onChange={(e) => updateSearchTransliteration(e.target.checked)}
+          />
+          kana/romaji folding
+        </label>
+        <input
+          placeholder="Search artifact text (tree.md, result.json, ...)"
+          value={artifactSearchQuery}
+          onChange={(e) => setArtifactSearchQuery(e.target.value)}
+          onKeyDown={(e) => {
+            if (e.key === "Enter") onSearchArtifacts();
+          }}
+          style={{ padding: 8, borderRadius: 6, border: "1px solid #ccc", minWidth: 240 }}
+        />
+        <button
+          onClick={onSearchArtifacts}
+          disabled={artifactSearchLoading}
+          style={{ padding: "8px 12px", borderRadius: 8, border: "1px solid #333" }}
+        >
+          {artifactSearchLoading ? "Searching..." : "Search artifacts"}
+        </button>
         <select
           value={libraryFilters.status}
           onChange={(e) => setLibraryFilters((prev) => ({ ...prev, status: e.target.value }))}
@@ -3486,6 +6006,18 @@ export default function App() {
           onChange={(e) => setLibraryFilters((prev) => ({ ...prev, tag: e.target.value }))}
           style={{ padding: 8, borderRadius: 6, border: "1px solid #ccc", width: 140 }}
         />
+        <select
+          value={libraryFilters.collection}
+          onChange={(e) => setLibraryFilters((prev) => ({ ...prev, collection: e.target.value }))}
+          style={{ padding: 8, borderRadius: 6, border: "1px solid #ccc" }}
+        >
+          <option value="">collection: all</option>
+          {collections.map((c) => (
+            <option key={c.collection_id} value={c.collection_id}>
+              {c.name} ({(c.paper_keys ?? []).length})
+            </option>
+          ))}
+        </select>
         <button
           onClick={() => loadLibraryRows(libraryFilters)}
           disabled={libraryLoading || isLibrarySearchMode}
@@ -3500,6 +6032,133 @@ export default function App() {
         >
           Reindex
         </button>
+        {libraryLoading ? (
+          <button
+            onClick={() => invoke("cancel_scan", { scanId: LIBRARY_REINDEX_SCAN_ID }).catch(() => {})}
+            style={{ padding: "8px 12px", borderRadius: 8, border: "1px solid #333" }}
+          >
+            Cancel reindex
+          </button>
+        ) : null}
+        <select
+          value={libraryExportFormat}
+          onChange={(e) => setLibraryExportFormat(e.target.value)}
+          style={{ padding: 8, borderRadius: 6, border: "1px solid #ccc" }}
+        >
+          <option value="csv">export: CSV</option>
+          <option value="bibtex">export: BibTeX</option>
+          <option value="json">export: JSON bundle</option>
+        </select>
+        <button
+          onClick={onLibraryExport}
+          disabled={libraryLoading}
+          title="Exports the records matching the current status/kind/tag filters"
+          style={{ padding: "8px 12px", borderRadius: 8, border: "1px solid #333" }}
+        >
+          Export...
+        </button>
+        <button
+          onClick={onLibraryExportBundle}
+          title="Tags, aliases, run notes and collections, for merging into another machine's library"
+          style={{ padding: "8px 12px", borderRadius: 8, border: "1px solid #333" }}
+        >
+          Export sync bundle...
+        </button>
+        <select
+          value={libraryMergeStrategy}
+          onChange={(e) => setLibraryMergeStrategy(e.target.value)}
+          title="How fields that differ on both machines are resolved"
+          style={{ padding: 8, borderRadius: 6, border: "1px solid #ccc" }}
+        >
+          <option value="union">merge: union</option>
+          <option value="keep_local">merge: keep local</option>
+          <option value="prefer_remote">merge: prefer remote</option>
+        </select>
+        <button
+          onClick={onLibraryImportBundle}
+          disabled={libraryLoading}
+          style={{ padding: "8px 12px", borderRadius: 8, border: "1px solid #333" }}
+        >
+          Import sync bundle...
+        </button>
+        <button
+          onClick={onEnrichAll}
+          disabled={libraryLoading || enrichStatus?.running === true}
+          style={{ padding: "8px 12px", borderRadius: 8, border: "1px solid #333" }}
+        >
+          Re-enrich missing
+        </button>
+        <label style={{ fontSize: 12, display: "flex", alignItems: "center", gap: 4 }}>
+          <input type="checkbox" checked={enrichFromS2} onChange={(e) => setEnrichFromS2(e.target.checked)} />
+          from S2
+        </label>
+        <button
+          onClick={loadEnrichStatus}
+          style={{ padding: "8px 12px", borderRadius: 8, border: "1px solid #333" }}
+        >
+          Enrich status
+        </button>
+        <select
+          value={externalRunFilter.status ?? ""}
+          onChange={(e) => setExternalRunFilter((prev) => ({ ...prev, status: e.target.value || null }))}
+          style={{ padding: 8, borderRadius: 6, border: "1px solid #ccc" }}
+        >
+          <option value="">import: any status</option>
+          <option value="succeeded">import: succeeded</option>
+          <option value="failed">import: failed</option>
+        </select>
+        <button
+          onClick={onImportExternalRuns}
+          disabled={libraryLoading}
+          style={{ padding: "8px 12px", borderRadius: 8, border: "1px solid #333" }}
+        >
+          Import runs...
+        </button>
+      </div>
+      {externalImportResult ? (
+        <div style={{ fontSize: 12, marginBottom: 8, opacity: 0.9 }}>
+          {externalImportResult.dry_run ? "nothing to import from" : "imported from"} {externalImportResult.source_dir}:{" "}
+          {(externalImportResult.imported ?? []).length} run(s),{" "}
+          {(externalImportResult.imported ?? []).filter((r) => r.renamed).length} renamed,{" "}
+          {(externalImportResult.warnings ?? []).length} warning(s)
+        </div>
+      ) : null}
+      {enrichStatus ? (
+        <div style={{ fontSize: 12, marginBottom: 8, opacity: 0.9 }}>
+          enrich: {enrichStatus.running ? "running" : "idle"} done={(enrichStatus.outcomes ?? []).length}/{enrichStatus.total ?? 0}
+          {" "}updated={(enrichStatus.outcomes ?? []).filter((o) => o.status === "updated").length}
+          {" "}failed={(enrichStatus.outcomes ?? []).filter((o) => o.status === "failed").length}
+        </div>
+      ) : null}
+
+      <div style={{ display: "flex", gap: 6, flexWrap: "wrap", alignItems: "center", marginBottom: 8, fontSize: 12 }}>
+        <span style={{ opacity: 0.8 }}>saved:</span>
+        {savedSearches.map((s) => (
+          <span key={s.name} style={{ display: "inline-flex", gap: 2 }}>
+            <button
+              onClick={() => onRunSavedSearch(s.name)}
+              title={[s.query, JSON.stringify(s.opts ?? {})].filter(Boolean).join(" ")}
+              style={{
+                padding: "4px 8px",
+                borderRadius: 6,
+                border: "1px solid #333",
+                background: activeSavedSearch === s.name ? "#eef" : undefined,
+              }}
+            >
+              {s.name}
+            </button>
+            {!s.builtin ? <button onClick={() => onDeleteSavedSearch(s.name)}>×</button> : null}
+          </span>
+        ))}
+        <input
+          value={saveSearchName}
+          onChange={(e) => setSaveSearchName(e.target.value)}
+          placeholder="save current search as..."
+          style={{ padding: 4, borderRadius: 6, border: "1px solid #ccc", width: 180 }}
+        />
+        <button onClick={onSaveCurrentSearch} disabled={!String(saveSearchName).trim()}>
+          Save
+        </button>
       </div>
 
       {libraryError ? <div style={{ color: "#a33", fontSize: 12, marginBottom: 8 }}>{libraryError}</div> : null}
@@ -3507,7 +6166,9 @@ export default function App() {
         <div style={{ fontSize: 12, marginBottom: 8, opacity: 0.9 }}>
           papers={libraryStats.total_papers} runs={libraryStats.total_runs}
           {isLibrarySearchMode ? ` | search_hits=${librarySearchRows.length}` : ""}
+          {activeSavedSearch ? ` | saved "${activeSavedSearch}": ${savedSearchRows.length}` : ""}
           {libraryReindexInfo ? ` | indexed_at=${libraryReindexInfo.updated_at}` : ""}
+          {libraryExportInfo ? ` | exported ${libraryExportInfo.count} to ${libraryExportInfo.path}` : ""}
         </div>
       ) : null}
 
@@ -3575,6 +6236,105 @@ export default function App() {
               Save tags
             </button>
           </div>
+          <div style={{ display: "flex", gap: 8, marginBottom: 8 }}>
+            <input
+              value={metaTitleInput}
+              onChange={(e) => setMetaTitleInput(e.target.value)}
+              placeholder="title"
+              style={{ flex: 2, padding: 8, borderRadius: 6, border: "1px solid #ccc" }}
+            />
+            <input
+              value={metaYearInput}
+              onChange={(e) => setMetaYearInput(e.target.value)}
+              placeholder="year"
+              style={{ width: 70, padding: 8, borderRadius: 6, border: "1px solid #ccc" }}
+            />
+            <input
+              value={metaCanonicalInput}
+              onChange={(e) => setMetaCanonicalInput(e.target.value)}
+              placeholder="canonical_id"
+              style={{ flex: 1, padding: 8, borderRadius: 6, border: "1px solid #ccc" }}
+            />
+            <button
+              onClick={onSaveMetadata}
+              disabled={!selectedPaperKey}
+              style={{ padding: "8px 12px", borderRadius: 8, border: "1px solid #333" }}
+            >
+              Save metadata
+            </button>
+          </div>
+          <div style={{ display: "flex", gap: 6, marginBottom: 8 }}>
+            <input
+              value={mergeKeyInput}
+              onChange={(e) => setMergeKeyInput(e.target.value)}
+              placeholder="paper_key of a duplicate record to merge into this one"
+              style={{ flex: 1, padding: 8, borderRadius: 6, border: "1px solid #ccc" }}
+            />
+            <button
+              onClick={onMergeLibraryRecord}
+              disabled={!selectedPaperKey || !String(mergeKeyInput).trim()}
+              style={{ padding: "8px 12px", borderRadius: 8, border: "1px solid #333" }}
+            >
+              Merge
+            </button>
+            <button
+              onClick={onEnrichFromS2}
+              disabled={!selectedPaperKey || !libraryDetail?.canonical_id}
+              style={{ padding: "8px 12px", borderRadius: 8, border: "1px solid #333" }}
+            >
+              Fetch from S2
+            </button>
+          </div>
+          <details style={{ marginBottom: 8 }}>
+            <summary style={{ fontSize: 12, cursor: "pointer" }}>Collections</summary>
+            <div style={{ marginTop: 6, display: "grid", gap: 4 }}>
+              {collections.map((c, i) => (
+                <div key={c.collection_id} style={{ display: "flex", gap: 6, alignItems: "center", fontSize: 12 }}>
+                  <input
+                    type="checkbox"
+                    checked={(c.paper_keys ?? []).includes(selectedPaperKey)}
+                    disabled={!selectedPaperKey}
+                    onChange={() => onToggleCollectionMembership(c)}
+                  />
+                  <span style={{ flex: 1 }}>{c.name}</span>
+                  <button onClick={() => onMoveCollection(c.collection_id, -1)} disabled={i === 0}>↑</button>
+                  <button onClick={() => onMoveCollection(c.collection_id, 1)} disabled={i === collections.length - 1}>↓</button>
+                </div>
+              ))}
+              <div style={{ display: "flex", gap: 6 }}>
+                <input
+                  value={newCollectionName}
+                  onChange={(e) => setNewCollectionName(e.target.value)}
+                  placeholder="new collection"
+                  style={{ flex: 1, padding: 6, borderRadius: 6, border: "1px solid #ccc" }}
+                />
+                <button onClick={onCreateCollection} disabled={!String(newCollectionName).trim()}>
+                  Create
+                </button>
+              </div>
+            </div>
+          </details>
+          {libraryDetail?.enrichment ? (
+            <div style={{ fontSize: 12, marginBottom: 8 }}>
+              {(libraryDetail.enrichment.authors ?? []).length > 0 ? (
+                <div>{libraryDetail.enrichment.authors.join(", ")}</div>
+              ) : null}
+              {libraryDetail.enrichment.venue ? <div style={{ opacity: 0.8 }}>{libraryDetail.enrichment.venue}</div> : null}
+              {libraryDetail.enrichment.abstract ? (
+                <details>
+                  <summary style={{ cursor: "pointer" }}>Abstract</summary>
+                  <div style={{ marginTop: 4, whiteSpace: "pre-wrap" }}>{libraryDetail.enrichment.abstract}</div>
+                </details>
+              ) : null}
+              <div style={{ fontSize: 11, opacity: 0.7 }}>from Semantic Scholar at {libraryDetail.enrichment.fetched_at}</div>
+            </div>
+          ) : null}
+          {(libraryDetail?.user_overrides ?? []).length > 0 || (libraryDetail?.aliases ?? []).length > 0 ? (
+            <div style={{ fontSize: 11, opacity: 0.8, marginBottom: 8 }}>
+              overridden: {(libraryDetail?.user_overrides ?? []).join(", ") || "-"}
+              {(libraryDetail?.aliases ?? []).length > 0 ? ` | aliases: ${libraryDetail.aliases.join(", ")}` : ""}
+            </div>
+          ) : null}
 
           <details open>
             <summary style={{ fontSize: 12, cursor: "pointer" }}>Run history</summary>
@@ -3582,14 +6342,37 @@ export default function App() {
               {(libraryDetail?.runs ?? []).map((r) => (
                 <div key={r.run_id} style={{ border: "1px solid #eee", borderRadius: 6, padding: 8 }}>
                   <div style={{ fontSize: 12 }}>run_id=<code>{r.run_id}</code> status=<code>{r.status}</code></div>
-                  <div style={{ fontSize: 11, opacity: 0.8 }}>template={r.template_id ?? "-"}</div>
+                  <div style={{ fontSize: 11, opacity: 0.8 }}>
+                    template={r.template_id ?? "-"}
+                    {r.pipeline_commit ? ` commit=${r.pipeline_commit.slice(0, 10)}` : ""}
+                  </div>
                   <div style={{ fontSize: 11, opacity: 0.8 }}>updated_at={r.updated_at}</div>
+                  {r.provenance ? (
+                    <div style={{ fontSize: 11, opacity: 0.8 }}>
+                      app={r.provenance.app_version} python={r.provenance.python_version ?? "-"}
+                      {r.provenance.job_id ? ` job=${r.provenance.job_id}` : ""}
+                      {r.provenance.pipeline_id ? ` pipeline=${r.provenance.pipeline_id}` : ""}
+                    </div>
+                  ) : null}
                   <button
                     onClick={() => onOpenRunFromLibrary(r.run_id)}
                     style={{ marginTop: 6, padding: "6px 10px", borderRadius: 6, border: "1px solid #333" }}
                   >
                     Open run detail
                   </button>
+                  <button
+                    onClick={() => onToggleRunManifest(r.run_id)}
+                    style={{ marginTop: 6, marginLeft: 6, padding: "6px 10px", borderRadius: 6, border: "1px solid #333" }}
+                  >
+                    {runManifests[r.run_id] ? "Hide manifest" : "Manifest"}
+                  </button>
+                  {runManifests[r.run_id] ? (
+                    <pre style={{ fontSize: 11, whiteSpace: "pre-wrap", marginTop: 6 }}>
+                      {runManifests[r.run_id].missing
+                        ? "No desktop_manifest.json in this run (created before manifests were written)."
+                        : runManifests[r.run_id].error ?? JSON.stringify(runManifests[r.run_id], null, 2)}
+                    </pre>
+                  ) : null}
                 </div>
               ))}
             </div>
@@ -3602,12 +6385,34 @@ export default function App() {
       <h3 style={{ marginBottom: 8 }}>Runs / Artifacts Viewer</h3>
       <div style={{ display: "flex", gap: 8, marginBottom: 8 }}>
         <button
-          onClick={loadRuns}
+          onClick={() => loadRuns()}
           disabled={runsLoading}
           style={{ padding: "8px 12px", borderRadius: 8, border: "1px solid #333" }}
         >
           {runsLoading ? "Refreshing..." : "Refresh runs"}
         </button>
+        <select
+          value={runGroupBy}
+          onChange={(e) => {
+            setRunGroupBy(e.target.value);
+            loadRuns(e.target.value);
+          }}
+          style={{ padding: 8, borderRadius: 6, border: "1px solid #ccc" }}
+        >
+          <option value="">no grouping</option>
+          <option value="pipeline">group by pipeline</option>
+          <option value="paper">group by paper</option>
+          <option value="template">group by template</option>
+        </select>
+        <input
+          value={runCommitFilter}
+          onChange={(e) => setRunCommitFilter(e.target.value)}
+          onKeyDown={(e) => {
+            if (e.key === "Enter") loadRuns();
+          }}
+          placeholder="pipeline commit (prefix)"
+          style={{ padding: 8, borderRadius: 6, border: "1px solid #ccc", width: 180 }}
+        />
         <button
           onClick={onOpenSelectedRunFolder}
           disabled={!selectedRun}
@@ -3615,6 +6420,20 @@ export default function App() {
         >
           Open selected run folder
         </button>
+        <button
+          onClick={onDeleteSelectedRun}
+          disabled={!selectedRun}
+          style={{ padding: "8px 12px", borderRadius: 8, border: "1px solid #a33", color: "#a33" }}
+        >
+          Delete run
+        </button>
+        <button
+          onClick={onArchiveSelectedRun}
+          disabled={!selectedRun}
+          style={{ padding: "8px 12px", borderRadius: 8 }}
+        >
+          Archive run...
+        </button>
       </div>
 
       {cfgError ? (
@@ -3627,8 +6446,36 @@ export default function App() {
         <div style={{ marginBottom: 8, color: "#a33", fontSize: 12 }}>{runsError}</div>
       ) : null}
 
+      {runGroupBy && runGroups.length > 0 ? (
+        <div style={{ border: "1px solid #ddd", borderRadius: 8, padding: 8, marginBottom: 8, fontSize: 12 }}>
+          {runGroups.map((g) => (
+            <div key={`${runGroupBy}:${g.key}`} style={{ marginBottom: 4 }}>
+              <strong>{g.label || "-"}</strong> [{g.rollup_status}] runs={g.run_ids?.length ?? 0}{" "}
+              {runGroupBy === "paper" && g.label !== g.key ? <code style={{ opacity: 0.75 }}>{g.key} </code> : null}
+              latest_status={g.latest_status}{" "}
+              {Object.entries(g.status_counts ?? {}).map(([k, v]) => `${k}=${v}`).join(" ")}{" "}
+              <button onClick={() => setSelectedRunId(g.latest_run_id)} style={{ fontSize: 11 }}>
+                latest: {g.latest_run_id}
+              </button>
+              {g.latest_primary_viz ? <span style={{ opacity: 0.75 }}> viz={g.latest_primary_viz.name}</span> : null}
+            </div>
+          ))}
+        </div>
+      ) : null}
+
       <div style={{ display: "grid", gridTemplateColumns: "1fr 2fr", gap: 12 }}>
-        <div style={{ border: "1px solid #ddd", borderRadius: 8, maxHeight: 260, overflow: "auto" }}>
+        <div
+          style={{ border: "1px solid #ddd", borderRadius: 8, maxHeight: 260, overflow: "auto" }}
+          onScroll={(e) => {
+            const el = e.currentTarget;
+            if (el.scrollTop + el.clientHeight >= el.scrollHeight - 40) loadMoreRuns();
+          }}
+        >
+          {runsPage?.total != null ? (
+            <div style={{ padding: "4px 10px", fontSize: 11, opacity: 0.7 }}>
+              {runs.length} of {runsPage.total} runs
+            </div>
+          ) : null}
           {runs.length === 0 ? (
             <div style={{ padding: 10, fontSize: 12, opacity: 0.8 }}>No runs found.</div>
           ) : (
@@ -3647,7 +6494,10 @@ export default function App() {
                 }}
               >
                 <div style={{ fontSize: 12, fontWeight: 600 }}>{row.run_id}</div>
-                <div style={{ fontSize: 11, opacity: 0.85 }}>status={row.status}</div>
+                <div style={{ fontSize: 11, opacity: 0.85 }}>
+                  status={row.status}
+                  {row.duration_ms != null ? ` (${Math.round(row.duration_ms / 1000)}s)` : ""}
+                </div>
                 <div style={{ fontSize: 11, opacity: 0.75 }}>paper_id={row.paper_id}</div>
               </button>
             ))
@@ -3667,6 +6517,7 @@ export default function App() {
               style={{ padding: 8, borderRadius: 6, border: "1px solid #ccc" }}
             >
               <option value="tree_md">tree.md (markdown)</option>
+              <option value="summary_md">summary.md (markdown)</option>
               <option value="result_json">result.json</option>
               <option value="input_json">input.json</option>
               <option value="stdout_log">stdout.log</option>
@@ -3674,8 +6525,62 @@ export default function App() {
             </select>
           </div>
 
+          <div style={{ border: "1px solid #eee", borderRadius: 6, padding: 8, marginBottom: 8 }}>
+            <div style={{ fontSize: 12, fontWeight: 600, marginBottom: 6 }}>Run notes</div>
+            <textarea
+              value={runNoteDraft}
+              onChange={(e) => setRunNoteDraft(e.target.value)}
+              rows={6}
+              placeholder="No note yet."
+              style={{ width: "100%", fontFamily: "monospace", fontSize: 12 }}
+            />
+            <div style={{ display: "flex", gap: 8, alignItems: "center", fontSize: 12 }}>
+              <button onClick={onSaveRunNote} disabled={!selectedRunId}>
+                Save note
+              </button>
+              {runNoteStatus ? <span style={{ opacity: 0.8 }}>{runNoteStatus}</span> : null}
+            </div>
+          </div>
+
           <div style={{ border: "1px solid #eee", borderRadius: 6, padding: 8, marginBottom: 8 }}>
             <div style={{ fontSize: 12, fontWeight: 600, marginBottom: 6 }}>Artifact catalog</div>
+            <div style={{ display: "flex", gap: 6, marginBottom: 6 }}>
+              <input
+                value={artifactSearchQuery}
+                onChange={(e) => setArtifactSearchQuery(e.target.value)}
+                onKeyDown={(e) => {
+                  if (e.key === "Enter") onSearchRunArtifacts();
+                }}
+                placeholder="search in artifacts (e.g. Traceback)"
+                style={{ flex: 1, padding: 4, borderRadius: 6, border: "1px solid #ccc", fontSize: 11 }}
+              />
+              <button
+                onClick={onSearchRunArtifacts}
+                style={{ padding: "4px 8px", borderRadius: 6, border: "1px solid #333", fontSize: 11 }}
+              >
+                Search
+              </button>
+            </div>
+            {artifactSearchResult ? (
+              <div style={{ fontSize: 11, marginBottom: 6 }}>
+                {artifactSearchResult.warning ? (
+                  <div style={{ color: "#8a4200" }}>{artifactSearchResult.warning}</div>
+                ) : null}
+                {artifactSearchResult.items.length === 0 ? <div style={{ opacity: 0.8 }}>No matches.</div> : null}
+                {artifactSearchResult.items.map((m) => (
+                  <div
+                    key={`${m.rel_path}:${m.line}`}
+                    style={{ cursor: "pointer", fontFamily: "monospace", whiteSpace: "pre-wrap" }}
+                    onClick={() => {
+                      const item = runArtifactCatalog.find((i) => i.rel_path === m.rel_path);
+                      if (item) onOpenCatalogArtifact(item);
+                    }}
+                  >
+                    <b>{m.rel_path}:{m.line}</b> {m.snippet}
+                  </div>
+                ))}
+              </div>
+            ) : null}
             {runArtifactCatalogLoading ? <div style={{ fontSize: 12 }}>Loading catalog...</div> : null}
             {runArtifactCatalogError ? <div style={{ color: "#a33", fontSize: 12 }}>{runArtifactCatalogError}</div> : null}
             {!runArtifactCatalogLoading && runArtifactCatalog.length === 0 ? (
@@ -3687,24 +6592,45 @@ export default function App() {
                   key={`${item.rel_path}:${item.name}`}
                   style={{
                     display: "grid",
-                    gridTemplateColumns: "1fr auto",
+                    gridTemplateColumns: item.thumbnail ? "auto 1fr auto" : "1fr auto",
                     gap: 8,
                     border: "1px solid #f0f0f0",
                     borderRadius: 6,
                     padding: 6,
                   }}
                 >
+                  {item.thumbnail ? (
+                    <img
+                      alt=""
+                      src={item.thumbnail}
+                      style={{ width: 48, height: 48, objectFit: "contain", border: "1px solid #eee", borderRadius: 4 }}
+                    />
+                  ) : null}
                   <div style={{ fontSize: 11 }}>
-                    <div style={{ fontWeight: 600 }}>{item.name}</div>
+                    <div style={{ fontWeight: 600 }} title={item.display_name ? item.rel_path : undefined}>
+                      {item.display_name || item.name}
+                    </div>
+                    {item.description ? <div style={{ opacity: 0.9 }}>{item.description}</div> : null}
                     <div style={{ opacity: 0.8 }}>kind={item.kind} size={item.size_bytes ?? "-"}</div>
                     <div style={{ opacity: 0.8 }}>mtime={item.mtime_iso ?? "-"}</div>
                   </div>
-                  <button
-                    onClick={() => onOpenCatalogArtifact(item)}
-                    style={{ padding: "4px 8px", borderRadius: 6, border: "1px solid #333", fontSize: 11 }}
-                  >
-                    Open
-                  </button>
+                  <div style={{ display: "flex", flexDirection: "column", gap: 4 }}>
+                    <button
+                      onClick={() => onOpenCatalogArtifact(item)}
+                      style={{ padding: "4px 8px", borderRadius: 6, border: "1px solid #333", fontSize: 11 }}
+                    >
+                      Open
+                    </button>
+                    {item.kind === "html" || item.kind === "graph_json" ? (
+                      <button
+                        onClick={() => onSetPrimaryViz(item)}
+                        title="Open this artifact first for this run"
+                        style={{ padding: "4px 8px", borderRadius: 6, border: "1px solid #333", fontSize: 11 }}
+                      >
+                        Set primary
+                      </button>
+                    ) : null}
+                  </div>
                 </div>
               ))}
             </div>
@@ -3727,8 +6653,19 @@ export default function App() {
             <div>
               <div style={{ fontSize: 11, opacity: 0.8, marginBottom: 6 }}>
                 artifact_path=<code>{artifactView.path}</code> parse_status=<code>{artifactView.parse_status}</code>
+                {artifactView.parse_status === "truncated" && ["text", "markdown", "unknown"].includes(artifactKind) ? (
+                  <button
+                    onClick={onReadArtifactRange}
+                    disabled={artifactLoading}
+                    style={{ marginLeft: 8, padding: "2px 6px", borderRadius: 6, border: "1px solid #333", fontSize: 11 }}
+                  >
+                    {artifactView.range_next == null
+                      ? "Read in parts"
+                      : `Read next part (${artifactView.range_next}/${artifactView.range_total} bytes)`}
+                  </button>
+                ) : null}
               </div>
-              {selectedArtifact === "tree_md" && artifactView.exists ? (
+              {(selectedArtifact === "tree_md" || selectedArtifact === "summary_md") && artifactView.exists ? (
                 <div
                   style={{
                     border: "1px solid #eee",
@@ -3743,13 +6680,45 @@ export default function App() {
                 />
               ) : isHtmlArtifact && artifactView.exists ? (
                 <div style={{ border: "1px solid #eee", borderRadius: 6, overflow: "hidden" }}>
-                  <iframe
-                    title="artifact-html-viewer"
-                    sandbox="allow-forms"
-                    srcDoc={artifactView.content ?? ""}
-                    style={{ width: "100%", height: 420, border: "none", background: "#fff" }}
-                  />
+                  <div style={{ fontSize: 11, padding: 4, borderBottom: "1px solid #eee" }}>
+                    {artifactView.interactive_content != null ? (
+                      <span>interactive mode (module scripts, no network)</span>
+                    ) : (
+                      <button
+                        onClick={onEnableInteractiveHtml}
+                        disabled={artifactLoading}
+                        style={{ padding: "2px 6px", borderRadius: 6, border: "1px solid #333", fontSize: 11 }}
+                      >
+                        Interactive mode...
+                      </button>
+                    )}
+                  </div>
+                  {artifactView.interactive_content != null ? (
+                    <iframe
+                      title="artifact-html-interactive"
+                      sandbox="allow-scripts"
+                      srcDoc={artifactView.interactive_content}
+                      style={{ width: "100%", height: 560, border: "none", background: "#fff" }}
+                    />
+                  ) : (
+                    <iframe
+                      title="artifact-html-viewer"
+                      sandbox="allow-forms"
+                      srcDoc={artifactView.content ?? ""}
+                      style={{ width: "100%", height: 420, border: "none", background: "#fff" }}
+                    />
+                  )}
                 </div>
+              ) : isImageArtifact && artifactView.exists ? (
+                artifactView.content ? (
+                  <img
+                    alt={artifactView.artifact}
+                    src={artifactView.content}
+                    style={{ maxWidth: "100%", border: "1px solid #eee", borderRadius: 6, background: "#fff" }}
+                  />
+                ) : (
+                  <div style={{ fontSize: 12, opacity: 0.8 }}>No inline preview. Use Open run folder.</div>
+                )
               ) : isGraphJsonArtifact && artifactView.exists ? (
                 <div>
                   {graphParseLoading ? <div style={{ fontSize: 12 }}>Parsing graph...</div> : null}
@@ -3767,6 +6736,73 @@ export default function App() {
                   >
                     <div>nodes={graphParsed?.stats?.nodes_count ?? 0} edges={graphParsed?.stats?.edges_count ?? 0}</div>
                     <div>top_keys={(graphParsed?.stats?.top_level_keys ?? []).join(", ") || "-"}</div>
+                    {graphAnalysis ? (
+                      <div style={{ marginTop: 4 }}>
+                        <div>
+                          components={graphAnalysis.components.count} (largest={graphAnalysis.components.largest}, isolated=
+                          {graphAnalysis.components.isolated}) density={graphAnalysis.density.toFixed(4)} avg_clustering=
+                          {graphAnalysis.average_clustering.toFixed(3)} transitivity={graphAnalysis.transitivity.toFixed(3)}
+                          {graphAnalysis.betweenness_sampled ? ` (betweenness sampled from ${graphAnalysis.betweenness_sources} nodes)` : ""}
+                        </div>
+                        <div>
+                          hubs:{" "}
+                          {graphAnalysis.hubs.map((h) => (
+                            <button
+                              key={h.id}
+                              onClick={() => setSelectedGraphNodeId(h.id)}
+                              title={`betweenness=${h.betweenness.toFixed(3)} clustering=${h.clustering.toFixed(3)}`}
+                              style={{ fontSize: 11, marginRight: 4 }}
+                            >
+                              {h.label ?? h.id} ({h.degree})
+                            </button>
+                          ))}
+                        </div>
+                      </div>
+                    ) : null}
+                    {graphMergeInfo ? (
+                      <div>
+                        merged from {(graphMergeInfo.sources ?? []).filter((src) => !src.error).map((src) => src.run_id).join(", ")}{" "}
+                        (duplicates: nodes={graphMergeInfo.duplicate_nodes} edges={graphMergeInfo.duplicate_edges})
+                        {selectedGraphNodeId && graphMergeInfo.node_sources?.[selectedGraphNodeId] ? (
+                          <span> | selected node from {graphMergeInfo.node_sources[selectedGraphNodeId].join(", ")}</span>
+                        ) : null}
+                      </div>
+                    ) : null}
+                    <div style={{ display: "flex", gap: 6, alignItems: "center", marginTop: 4, flexWrap: "wrap" }}>
+                      <button onClick={() => onCopyGraphAsDot(null)} disabled={!graphParsed}>
+                        Copy as DOT
+                      </button>
+                      <button
+                        onClick={() => onCopyGraphAsDot(filteredGraphNodes.map((n) => n.id))}
+                        disabled={!graphParsed || filteredGraphNodes.length === 0}
+                      >
+                        Copy filtered as DOT
+                      </button>
+                      <button onClick={onExportRunBibtex} disabled={!graphParsed}>
+                        Export BibTeX
+                      </button>
+                      <button onClick={onMergeGraphs} disabled={!graphParsed}>
+                        Merge with runs...
+                      </button>
+                      <button onClick={onAnalyzeGraph} disabled={!graphParsed}>
+                        Analyze
+                      </button>
+                      <button onClick={onEgoNetwork} disabled={!graphParsed || !selectedGraphNodeId}>
+                        Ego network...
+                      </button>
+                      {graphDotStatus ? <span style={{ opacity: 0.8 }}>{graphDotStatus}</span> : null}
+                    </div>
+                    {graphParsed?.slice ? (
+                      <div style={{ display: "flex", gap: 6, alignItems: "center", marginTop: 4 }}>
+                        <span>
+                          server slice: showing {graphParsed.nodes.length} of {graphParsed.slice.matched} matched
+                          {graphParsed.slice.truncated ? " (truncated)" : ""}
+                        </span>
+                        <button onClick={() => onQueryGraphSlice()} disabled={graphParseLoading}>
+                          Refresh slice
+                        </button>
+                      </div>
+                    ) : null}
                     {(graphParsed?.warnings ?? []).length > 0 ? (
                       <div style={{ color: "#8a4200" }}>warnings={(graphParsed?.warnings ?? []).join(" | ")}</div>
                     ) : null}
@@ -3970,6 +7006,77 @@ export default function App() {
                   />
                 </div>
               )}
+              {artifactView.exists && !artifactView.graph_session ? (
+                <div style={{ marginTop: 8, fontSize: 12, borderTop: "1px solid #eee", paddingTop: 6 }}>
+                  <div style={{ fontWeight: 600, marginBottom: 4 }}>
+                    Annotations ({(artifactView.annotations ?? []).length})
+                  </div>
+                  {(artifactView.annotations ?? []).map((a) => (
+                    <div
+                      key={a.annotation_id}
+                      style={{ background: "#fff8d6", borderLeft: "3px solid #e0b400", padding: "2px 6px", marginBottom: 3 }}
+                    >
+                      <code>
+                        {a.anchor?.type === "node"
+                          ? `node ${a.anchor.node_id}`
+                          : `L${a.anchor?.start}${a.anchor?.end > a.anchor?.start ? `-${a.anchor.end}` : ""}`}
+                      </code>{" "}
+                      {a.text}{" "}
+                      <button onClick={() => onDeleteArtifactAnnotation(a.annotation_id)} style={{ fontSize: 11 }}>
+                        x
+                      </button>
+                    </div>
+                  ))}
+                  <div style={{ display: "flex", gap: 6, flexWrap: "wrap", alignItems: "center" }}>
+                    <select
+                      value={annotationDraft.type}
+                      onChange={(e) => setAnnotationDraft((prev) => ({ ...prev, type: e.target.value }))}
+                    >
+                      <option value="lines">lines</option>
+                      <option value="node">graph node</option>
+                    </select>
+                    {annotationDraft.type === "node" ? (
+                      <input
+                        placeholder={selectedGraphNodeId || "node id"}
+                        value={annotationDraft.nodeId}
+                        onChange={(e) => setAnnotationDraft((prev) => ({ ...prev, nodeId: e.target.value }))}
+                        style={{ width: 140 }}
+                      />
+                    ) : (
+                      <>
+                        <input
+                          type="number"
+                          min={1}
+                          value={annotationDraft.start}
+                          onChange={(e) => setAnnotationDraft((prev) => ({ ...prev, start: e.target.value }))}
+                          style={{ width: 60 }}
+                        />
+                        -
+                        <input
+                          type="number"
+                          min={1}
+                          placeholder="end"
+                          value={annotationDraft.end}
+                          onChange={(e) => setAnnotationDraft((prev) => ({ ...prev, end: e.target.value }))}
+                          style={{ width: 60 }}
+                        />
+                      </>
+                    )}
+                    <input
+                      placeholder="note"
+                      value={annotationDraft.text}
+                      onChange={(e) => setAnnotationDraft((prev) => ({ ...prev, text: e.target.value }))}
+                      style={{ flex: 1, minWidth: 160 }}
+                    />
+                    <button onClick={onAddArtifactAnnotation} disabled={!annotationDraft.text.trim()}>
+                      Add note
+                    </button>
+                    <button onClick={reloadArtifactAnnotations}>Reload</button>
+                    <button onClick={onExportArtifactHtml}>Export HTML</button>
+                  </div>
+                  {annotationStatus ? <div style={{ opacity: 0.8, marginTop: 4 }}>{annotationStatus}</div> : null}
+                </div>
+              ) : null}
             </div>
           ) : null}
         </div>
@@ -4230,6 +7337,302 @@ export default function App() {
               />
               Auto-retry enabled
             </label>
+            <label style={{ display: "flex", alignItems: "center", gap: 6, fontSize: 12 }}>
+              <input
+                type="checkbox"
+                checked={desktopSettings?.auto_retry_scheduler_enabled !== false}
+                disabled={!desktopSettings || settingsLoading}
+                onChange={(e) => updateAutoRetryScheduler(e.target.checked)}
+              />
+              Retry in background (every {desktopSettings?.auto_retry_tick_seconds ?? 30}s at most)
+            </label>
+            <label style={{ display: "flex", alignItems: "center", gap: 6, fontSize: 12 }}>
+              <input
+                type="checkbox"
+                checked={desktopSettings?.offline_mode === true}
+                disabled={!desktopSettings || settingsLoading}
+                onChange={(e) => updateOfflineMode(e.target.checked)}
+              />
+              Offline mode (block templates that call external APIs)
+            </label>
+            <div style={{ display: "flex", alignItems: "center", gap: 6, fontSize: 12 }}>
+              Run folders:
+              <select
+                value={desktopSettings?.run_layout ?? "flat"}
+                disabled={!desktopSettings || settingsLoading}
+                onChange={(e) => updateRunLayout(e.target.value)}
+              >
+                <option value="flat">flat (out_dir/run_id)</option>
+                <option value="by_paper">per paper (out_dir/paper/run_id)</option>
+              </select>
+              <button type="button" onClick={reorganizeRuns} disabled={!desktopSettings}>
+                Reorganize existing runs
+              </button>
+            </div>
+            <div style={{ display: "flex", alignItems: "center", gap: 6, fontSize: 12 }}>
+              <button type="button" onClick={loadRateLimitStatus}>
+                S2 budget
+              </button>
+              {rateLimitStatus ? (
+                rateLimitStatus.enabled ? (
+                  <span>
+                    {rateLimitStatus.used_in_window}+{rateLimitStatus.reserved} / {rateLimitStatus.max_requests} in{" "}
+                    {rateLimitStatus.window_seconds}s
+                    {rateLimitStatus.held_job_ids.length > 0 ? `, holding ${rateLimitStatus.held_job_ids.length} job(s)` : ""}
+                    {rateLimitStatus.resets_in_seconds != null ? `, frees up in ${rateLimitStatus.resets_in_seconds}s` : ""}
+                  </span>
+                ) : (
+                  <span>off ({rateLimitStatus.used_in_window} requests in last {rateLimitStatus.window_seconds}s)</span>
+                )
+              ) : null}
+            </div>
+            <label style={{ display: "flex", alignItems: "center", gap: 6, fontSize: 12 }}>
+              <input
+                type="checkbox"
+                checked={desktopSettings?.auto_run_notes === true}
+                disabled={!desktopSettings || settingsLoading}
+                onChange={(e) => updateAutoRunNotes(e.target.checked)}
+              />
+              Auto-create run notes
+            </label>
+            <label
+              style={{ display: "flex", alignItems: "center", gap: 6, fontSize: 12 }}
+              title="Show paper-001 style pseudonyms instead of canonical ids and titles (display only)"
+            >
+              <input
+                type="checkbox"
+                checked={desktopSettings?.ui?.privacy_display_mode === true}
+                disabled={!desktopSettings || settingsLoading}
+                onChange={(e) => updatePrivacyDisplayMode(e.target.checked)}
+              />
+              Privacy display mode
+            </label>
+            <label
+              style={{ display: "flex", alignItems: "center", gap: 6, fontSize: 12 }}
+              title={
+                Object.keys(desktopSettings?.queue?.template_concurrency ?? {}).length > 0
+                  ? `per-template caps: ${Object.entries(desktopSettings.queue.template_concurrency)
+                      .map(([k, v]) => `${k}=${v}`)
+                      .join(", ")}`
+                  : "No per-template caps (queue.template_concurrency in settings.json)"
+              }
+            >
+              Parallel jobs
+              <input
+                type="number"
+                min={1}
+                max={8}
+                value={desktopSettings?.queue?.max_concurrent_jobs ?? 1}
+                disabled={!desktopSettings || settingsLoading}
+                onChange={(e) => updateMaxConcurrentJobs(e.target.value)}
+                style={{ width: 48 }}
+              />
+            </label>
+            <label
+              style={{ display: "flex", alignItems: "center", gap: 6, fontSize: 12 }}
+              title={
+                outDirWatchStatus?.last_error
+                  ? `watcher error: ${outDirWatchStatus.last_error}`
+                  : outDirWatchStatus?.watching
+                    ? `watching ${outDirWatchStatus.watching}; ${outDirWatchStatus.synced_runs} runs synced, last at ${outDirWatchStatus.last_sync_at ?? "-"}`
+                    : "Re-index runs created outside the app"
+              }
+            >
+              <input
+                type="checkbox"
+                checked={desktopSettings?.watcher?.enabled !== false}
+                disabled={!desktopSettings || settingsLoading}
+                onChange={(e) => updateWatchOutDir(e.target.checked)}
+              />
+              Watch out_dir
+              {outDirWatchStatus?.last_error ? <span style={{ color: "#a33" }}>!</span> : null}
+            </label>
+            <label style={{ display: "flex", alignItems: "center", gap: 6, fontSize: 12 }}>
+              Keep runs
+              <input
+                type="number"
+                min={1}
+                placeholder="max"
+                value={desktopSettings?.retention?.max_runs ?? ""}
+                disabled={!desktopSettings || settingsLoading}
+                onChange={(e) => updateRetention({ max_runs: e.target.value ? Number(e.target.value) : null })}
+                style={{ width: 56 }}
+              />
+              <input
+                type="number"
+                min={1}
+                placeholder="days"
+                value={desktopSettings?.retention?.max_age_days ?? ""}
+                disabled={!desktopSettings || settingsLoading}
+                onChange={(e) => updateRetention({ max_age_days: e.target.value ? Number(e.target.value) : null })}
+                style={{ width: 56 }}
+              />
+              <input
+                type="number"
+                min={0.1}
+                step={0.1}
+                placeholder="GB"
+                value={desktopSettings?.retention?.max_total_gb ?? ""}
+                disabled={!desktopSettings || settingsLoading}
+                onChange={(e) => updateRetention({ max_total_gb: e.target.value ? Number(e.target.value) : null })}
+                style={{ width: 56 }}
+              />
+              <select
+                value={desktopSettings?.retention?.action ?? "trash"}
+                disabled={!desktopSettings || settingsLoading}
+                onChange={(e) => updateRetention({ action: e.target.value })}
+              >
+                <option value="trash">to trash</option>
+                <option value="delete">delete</option>
+                <option value="archive">archive</option>
+              </select>
+              <button onClick={onLoadStorageReport}>Storage</button>
+              <button onClick={onLoadTrash}>Trash</button>
+              <button onClick={onLoadSchemaBackups}>Schema backups</button>
+            </label>
+            <label style={{ display: "flex", alignItems: "center", gap: 6, fontSize: 12 }}>
+              <button onClick={() => setNotificationsOpen((v) => !v)}>
+                Notifications{notificationFeed.unread_count > 0 ? ` (${notificationFeed.unread_count})` : ""}
+              </button>
+            </label>
+            {notificationsOpen ? (
+              <div style={{ flexBasis: "100%", fontSize: 12, border: "1px solid #eee", borderRadius: 6, padding: 6 }}>
+                <div style={{ display: "flex", gap: 10, flexWrap: "wrap", alignItems: "center" }}>
+                  {[
+                    ["desktop", "desktop popups"],
+                    ["job_finished", "job finished"],
+                    ["pipeline_failed", "pipeline failed"],
+                    ["auto_retry", "auto-retry"],
+                    ["retention", "retention"],
+                  ].map(([key, label]) => (
+                    <label key={key} style={{ display: "flex", alignItems: "center", gap: 4 }}>
+                      <input
+                        type="checkbox"
+                        checked={desktopSettings?.notifications?.[key] !== false}
+                        disabled={!desktopSettings || settingsLoading}
+                        onChange={(e) => updateNotificationPrefs({ [key]: e.target.checked })}
+                      />
+                      {label}
+                    </label>
+                  ))}
+                  <button onClick={() => onMarkNotificationRead("all")} disabled={notificationFeed.unread_count === 0}>
+                    Mark all read
+                  </button>
+                </div>
+                <div style={{ display: "flex", gap: 6, flexWrap: "wrap", alignItems: "center", marginTop: 4 }}>
+                  <span>Webhook{desktopSettings?.webhook?.url ? ` (${desktopSettings.webhook.url})` : ""}:</span>
+                  <input
+                    placeholder="https://example.com/hook (empty = off)"
+                    value={webhookDraft.url}
+                    onChange={(e) => setWebhookDraft((d) => ({ ...d, url: e.target.value }))}
+                    style={{ minWidth: 260 }}
+                  />
+                  <input
+                    type="password"
+                    placeholder="secret (optional)"
+                    value={webhookDraft.secret}
+                    onChange={(e) => setWebhookDraft((d) => ({ ...d, secret: e.target.value }))}
+                  />
+                  <button onClick={saveWebhook} disabled={!desktopSettings || settingsLoading}>
+                    Save webhook
+                  </button>
+                </div>
+                {notificationFeed.items.length === 0 ? <div style={{ opacity: 0.7 }}>No notifications.</div> : null}
+                {notificationFeed.items.map((n) => (
+                  <div key={n.id} style={{ display: "flex", gap: 6, alignItems: "center", opacity: n.read ? 0.6 : 1 }}>
+                    <code>{n.kind}</code>
+                    <span style={{ fontWeight: n.read ? 400 : 600 }}>{n.title}</span>
+                    <span>{n.body}</span>
+                    <span style={{ opacity: 0.7 }}>{n.created_at}</span>
+                    {!n.read ? <button onClick={() => onMarkNotificationRead(n.id)}>Read</button> : null}
+                  </div>
+                ))}
+              </div>
+            ) : null}
+            {storageReport ? (
+              <div style={{ flexBasis: "100%", fontSize: 12, border: "1px solid #eee", borderRadius: 6, padding: 6 }}>
+                <div>
+                  total={(storageReport.total_bytes / 1024 / 1024).toFixed(1)} MB (runs=
+                  {(storageReport.runs_bytes / 1024 / 1024).toFixed(1)} MB, state=
+                  {(storageReport.workspace_state_bytes / 1024 / 1024).toFixed(1)} MB, trash=
+                  {(storageReport.trash_bytes / 1024 / 1024).toFixed(1)} MB) runs={storageReport.runs.length}
+                  {storageReport.last_sweep ? ` | last sweep ${storageReport.last_sweep.finished_at}: removed ${storageReport.last_sweep.removed.length}` : ""}
+                </div>
+                <div>
+                  largest:{" "}
+                  {storageReport.runs
+                    .slice(0, 5)
+                    .map((r) => `${r.run_id} (${(r.bytes / 1024 / 1024).toFixed(1)} MB)`)
+                    .join(", ") || "-"}
+                </div>
+                <div style={{ display: "flex", gap: 6, alignItems: "center" }}>
+                  <span>retention would remove: {storageReport.retention_candidates.join(", ") || "-"}</span>
+                  <button onClick={onSweepRetentionNow} disabled={storageReport.retention_candidates.length === 0}>
+                    Sweep now
+                  </button>
+                  <button onClick={() => setStorageReport(null)}>Close</button>
+                </div>
+              </div>
+            ) : null}
+            {trashItems ? (
+              <div style={{ flexBasis: "100%", fontSize: 12, border: "1px solid #eee", borderRadius: 6, padding: 6 }}>
+                {trashItems.length === 0 ? <div style={{ opacity: 0.7 }}>Trash is empty.</div> : null}
+                {trashItems.map((item) => (
+                  <div key={item.id} style={{ display: "flex", gap: 6, alignItems: "center" }}>
+                    <code>{item.kind}</code>
+                    <span>
+                      {item.kind === "jobs" ? `${item.ids.length} job(s)` : item.ids[0]} (
+                      {(item.bytes / 1024 / 1024).toFixed(1)} MB)
+                    </span>
+                    <span style={{ opacity: 0.7 }}>
+                      trashed {new Date(item.trashed_at_ms).toLocaleString()}
+                      {item.expires_at_ms != null ? `, emptied after ${new Date(item.expires_at_ms).toLocaleDateString()}` : ""}
+                    </span>
+                    <button onClick={() => onRestoreFromTrash(item.id)}>Restore</button>
+                  </div>
+                ))}
+                <button onClick={() => setTrashItems(null)}>Close</button>
+              </div>
+            ) : null}
+            {schemaBackups ? (
+              <div style={{ flexBasis: "100%", fontSize: 12, border: "1px solid #eee", borderRadius: 6, padding: 6 }}>
+                {schemaBackups.length === 0 ? <div style={{ opacity: 0.7 }}>No schema backups.</div> : null}
+                {schemaBackups.map((backup) => (
+                  <div key={backup.backup_id} style={{ display: "flex", gap: 6, alignItems: "center" }}>
+                    <code>{backup.file}</code>
+                    <span>v{backup.schema_version}</span>
+                    <span style={{ opacity: 0.7 }}>
+                      {backup.reason === "rollback" ? "before rollback" : "before migration"},{" "}
+                      {new Date(backup.created_at_ms).toLocaleString()}
+                    </span>
+                    <button onClick={() => onRollbackSchema(backup)}>Roll back</button>
+                  </div>
+                ))}
+                <button onClick={() => setSchemaBackups(null)}>Close</button>
+              </div>
+            ) : null}
+            <label style={{ display: "flex", alignItems: "center", gap: 6, fontSize: 12 }}>
+              Primary viz
+              <select
+                value={desktopSettings?.primary_viz_policy?.mode ?? "prefer_html"}
+                disabled={!desktopSettings || settingsLoading}
+                onChange={(e) => updatePrimaryVizPolicy(e.target.value, vizPatternDraft || "*.html")}
+              >
+                <option value="prefer_html">prefer html</option>
+                <option value="prefer_graph_json">prefer graph json</option>
+                <option value="prefer_largest">prefer largest</option>
+                <option value="prefer_named_pattern">prefer name pattern</option>
+              </select>
+            </label>
+            {desktopSettings?.primary_viz_policy?.mode === "prefer_named_pattern" ? (
+              <input
+                value={vizPatternDraft || desktopSettings?.primary_viz_policy?.pattern || ""}
+                onChange={(e) => setVizPatternDraft(e.target.value)}
+                onBlur={() => updatePrimaryVizPolicy("prefer_named_pattern", vizPatternDraft)}
+                placeholder="e.g. *map*.html"
+                style={{ fontSize: 12, padding: 4 }}
+              />
+            ) : null}
           </div>
 
           <div style={{ border: "1px solid #ddd", borderRadius: 8, padding: 10, marginBottom: 12, fontSize: 12 }}>
@@ -4256,6 +7659,40 @@ export default function App() {
               </div>
             )}
             <div style={{ marginTop: 6, opacity: 0.8 }}>duration samples: {runDashboardStats?.duration_sample_count ?? 0}</div>
+            {jobDashboardStats ? (
+              <div style={{ marginTop: 8 }}>
+                <div style={{ fontWeight: 600 }}>Jobs (last {jobDashboardStats.days} days)</div>
+                <div>
+                  now: {Object.entries(jobDashboardStats.jobs_by_status ?? {}).map(([k, v]) => `${k}=${v}`).join(" ") || "-"}
+                </div>
+                <div>
+                  finished={jobDashboardStats.finished_in_window} failure_rate=
+                  {Number(jobDashboardStats.failure_rate_pct ?? 0).toFixed(1)}% needs_retry=
+                  {Number(jobDashboardStats.needs_retry_rate_pct ?? 0).toFixed(1)}%
+                </div>
+                {Object.entries(jobDashboardStats.avg_duration_by_template ?? {}).map(([template, d]) => (
+                  <div key={template} style={{ opacity: 0.85 }}>
+                    {template}: avg {Number(d.avg_sec).toFixed(1)}s ({d.samples} runs)
+                  </div>
+                ))}
+                <div style={{ display: "flex", alignItems: "flex-end", gap: 2, height: 40, marginTop: 4 }}>
+                  {(jobDashboardStats.daily ?? []).map((d) => {
+                    const total = Object.values(d.finished ?? {}).reduce((a, b) => a + b, 0);
+                    const peak = Math.max(
+                      1,
+                      ...(jobDashboardStats.daily ?? []).map((x) => Object.values(x.finished ?? {}).reduce((a, b) => a + b, 0)),
+                    );
+                    return (
+                      <div
+                        key={d.date}
+                        title={`${d.date}: ${total} finished, max queue ${d.queue_depth_max}`}
+                        style={{ width: 6, height: `${(total / peak) * 100}%`, background: d.finished?.failed ? "#c66" : "#6a9" }}
+                      />
+                    );
+                  })}
+                </div>
+              </div>
+            ) : null}
           </div>
 
           <div style={{ border: "1px solid #ddd", borderRadius: 8, padding: 10, marginBottom: 12, fontSize: 12 }}>
@@ -4270,6 +7707,18 @@ export default function App() {
             <div style={{ marginTop: 4, opacity: 0.8 }}>
               tick={tickResult?.reason ?? "-"} acted={tickResult?.acted ? "yes" : "no"}
             </div>
+            <div style={{ marginTop: 6, display: "flex", alignItems: "center", gap: 6 }}>
+              <select value={settingsResetSection} onChange={(e) => setSettingsResetSection(e.target.value)}>
+                {["auto_retry", "queue", "retention", "notifications", "watcher", "ui"].map((section) => (
+                  <option key={section} value={section}>
+                    {section}
+                  </option>
+                ))}
+              </select>
+              <button onClick={onResetSettingsSection} disabled={!desktopSettings || settingsLoading}>
+                Reset section
+              </button>
+            </div>
             {settingsError ? <div style={{ marginTop: 4, color: "#c00" }}>{settingsError}</div> : null}
           </div>
 
@@ -4307,6 +7756,43 @@ export default function App() {
               >
                 {collectingDiagnostics ? "Collecting..." : "Collect Diagnostics"}
               </button>
+              <select
+                value={diagRedaction}
+                onChange={(e) => {
+                  setDiagRedaction(e.target.value);
+                  setDiagPreview(null);
+                }}
+                title="Redaction profile for copied files and the audit tail"
+                style={{ fontSize: 11 }}
+              >
+                <option value="strict">redact: strict</option>
+                <option value="standard">redact: standard</option>
+                <option value="none">redact: none</option>
+              </select>
+              <button
+                onClick={onPreviewDiagnostics}
+                style={{ padding: "4px 8px", borderRadius: 6, border: "1px solid #333", fontSize: 11 }}
+              >
+                Preview
+              </button>
+              {devCommandsEnabled && (
+                <>
+                  <button
+                    onClick={onRunBenchmarks}
+                    disabled={benchmarkBusy}
+                    style={{ padding: "4px 8px", borderRadius: 6, border: "1px solid #333", fontSize: 11 }}
+                  >
+                    {benchmarkBusy ? "Benchmarking..." : "Run benchmarks"}
+                  </button>
+                  <button
+                    onClick={onGenerateSyntheticOutDir}
+                    disabled={benchmarkBusy}
+                    style={{ padding: "4px 8px", borderRadius: 6, border: "1px solid #333", fontSize: 11 }}
+                  >
+                    Synthetic out_dir...
+                  </button>
+                </>
+              )}
               <button
                 onClick={onGenerateDiagnosticsZipOneClick}
                 disabled={collectingDiagnostics || diagnosticsOneClickBusy}
@@ -4358,6 +7844,103 @@ export default function App() {
               </div>
             ) : null}
             {diagnosticsError ? <div style={{ color: "#c00", fontSize: 12, marginBottom: 6 }}>{diagnosticsError}</div> : null}
+            <div style={{ display: "flex", gap: 6, alignItems: "center", marginBottom: 6, fontSize: 11 }}>
+              <input
+                value={auditQuery}
+                onChange={(e) => setAuditQuery(e.target.value)}
+                onKeyDown={(e) => {
+                  if (e.key === "Enter") loadAuditEvents();
+                }}
+                placeholder="audit: event name or text"
+                style={{ fontSize: 11, width: 200 }}
+              />
+              <button
+                onClick={loadAuditEvents}
+                style={{ padding: "4px 8px", borderRadius: 6, border: "1px solid #333", fontSize: 11 }}
+              >
+                Audit log
+              </button>
+              {auditEvents ? <button onClick={() => setAuditEvents(null)}>Hide</button> : null}
+            </div>
+            {auditEvents ? (
+              <div style={{ fontSize: 11, maxHeight: 200, overflow: "auto", border: "1px solid #eee", borderRadius: 6, padding: 6, marginBottom: 6 }}>
+                {auditEvents.length === 0 ? <div>(no events)</div> : null}
+                {auditEvents.map((ev, i) => (
+                  <div key={`${ev.ts}-${i}`} title={JSON.stringify(ev.entry)}>
+                    {ev.ts} <b>{ev.event}</b>
+                    {ev.job_id ? ` job=${ev.job_id}` : ""}
+                    {ev.pipeline_id ? ` pipeline=${ev.pipeline_id}` : ""}
+                    {ev.run_id ? ` run=${ev.run_id}` : ""}
+                    {ev.canonical_id ? ` ${ev.canonical_id}` : ""}
+                  </div>
+                ))}
+              </div>
+            ) : null}
+            {diagPreview ? (
+              <div style={{ fontSize: 11, border: "1px solid #eee", borderRadius: 6, padding: 6, marginBottom: 6 }}>
+                <div>
+                  preview ({diagPreview.redaction}): {diagPreview.files.filter((f) => f.included).length} files,{" "}
+                  {diagPreview.total_included_bytes} / {diagPreview.max_total_bytes} bytes, audit tail{" "}
+                  {diagPreview.audit_tail_lines} lines
+                  {diagPreview.audit_redactions.length > 0 ? ` (${diagPreview.audit_redactions.join(", ")})` : ""}
+                </div>
+                {diagPreview.files.map((f) => (
+                  <div key={f.rel_path} style={{ opacity: f.included ? 1 : 0.6 }}>
+                    {f.included ? "+" : "-"} {f.rel_path}
+                    {f.included ? ` ${f.size_bytes} bytes` : ` (${f.reason})`}
+                    {f.redactions.length > 0 ? ` redact: ${f.redactions.join(", ")}` : ""}
+                  </div>
+                ))}
+              </div>
+            ) : null}
+            {runtimeCacheStats ? (
+              <div style={{ fontSize: 11, opacity: 0.8, marginBottom: 6 }}>
+                runtime config cache: hits={runtimeCacheStats.hits} (avg {runtimeCacheStats.avg_hit_us.toFixed(1)}us) misses=
+                {runtimeCacheStats.misses} (avg {runtimeCacheStats.avg_miss_us.toFixed(1)}us, last{" "}
+                {runtimeCacheStats.last_miss_us.toFixed(1)}us)
+              </div>
+            ) : null}
+            {runMetaCacheStats ? (
+              <div style={{ fontSize: 11, opacity: 0.8, marginBottom: 6 }}>
+                run metadata cache: entries={runMetaCacheStats.entries} hits={runMetaCacheStats.hits} misses=
+                {runMetaCacheStats.misses} invalidations={runMetaCacheStats.invalidations}
+              </div>
+            ) : null}
+            {refreshHints?.views ? (
+              <div style={{ fontSize: 11, opacity: 0.8, marginBottom: 6 }}>
+                polling:{" "}
+                {Object.entries(refreshHints.views)
+                  .map(([view, h]) => `${view}=${h.interval_ms == null ? "on change" : `${h.interval_ms}ms`} (${h.reason})`)
+                  .join(", ")}
+              </div>
+            ) : null}
+            {benchmarkReport ? (
+              <div style={{ fontSize: 11, marginBottom: 6 }}>
+                {(benchmarkReport.cases ?? []).map((c) => (
+                  <div key={c.name}>
+                    <code>{c.name}</code> n={c.size} mean={Number(c.mean_ms ?? 0).toFixed(1)}ms min={Number(c.min_ms ?? 0).toFixed(1)}ms
+                    {c.budget_ms != null ? ` budget=${Number(c.budget_ms).toFixed(1)}ms` : ""}
+                    {c.over_budget ? " OVER BUDGET" : ""}
+                  </div>
+                ))}
+                <div style={{ opacity: 0.75 }}>report: {benchmarkReport.report_path}</div>
+              </div>
+            ) : null}
+            {syntheticReport ? (
+              <div style={{ fontSize: 11, marginBottom: 6 }}>
+                synthetic out_dir ({syntheticReport.profile}): <code>{syntheticReport.out_dir}</code> runs=
+                {syntheticReport.runs_created} legacy={syntheticReport.legacy_runs} unicode={syntheticReport.unicode_runs}{" "}
+                graph_nodes={syntheticReport.graph_nodes_total} library={syntheticReport.library_records}{" "}
+                {Object.entries(syntheticReport.by_status ?? {})
+                  .map(([k, v]) => `${k}=${v}`)
+                  .join(" ")}
+                {syntheticReport.previous_out_dir ? (
+                  <button onClick={onRestoreOutDirAfterSynthetic} style={{ marginLeft: 8, fontSize: 11 }}>
+                    Switch back to {syntheticReport.previous_out_dir}
+                  </button>
+                ) : null}
+              </div>
+            ) : null}
             {diagnosticsRows.length === 0 ? (
               <div style={{ fontSize: 12, opacity: 0.8 }}>No diagnostic bundles.</div>
             ) : (
@@ -4427,7 +8010,10 @@ export default function App() {
                     <div key={p.pipeline_id} style={{ border: "1px solid #eee", borderRadius: 6, padding: 8 }}>
                       <div style={{ fontSize: 12, fontWeight: 600 }}>{p.pipeline_id}</div>
                       <div style={{ fontSize: 11 }}>{p.name} / {p.canonical_id}</div>
-                      <div style={{ fontSize: 11 }}>status={p.status} current_step={Math.min((p.current_step_index ?? 0) + 1, p.total_steps ?? 0)}/{p.total_steps} updated_at={p.updated_at}</div>
+                      <div style={{ fontSize: 11 }}>
+                        status={p.status} current_step={Math.min((p.current_step_index ?? 0) + 1, p.total_steps ?? 0)}/{p.total_steps} updated_at={p.updated_at}
+                        {pipelineEtaText(p) ? ` eta=${pipelineEtaText(p)}` : ""}
+                      </div>
                       <div style={{ display: "flex", gap: 6, marginTop: 6, flexWrap: "wrap" }}>
                         <button
                           onClick={async () => {
@@ -4526,6 +8112,29 @@ export default function App() {
                 Reload workspace history
               </button>
             </div>
+            <div style={{ display: "flex", gap: 8, marginBottom: 8, flexWrap: "wrap", alignItems: "center" }}>
+              <span style={{ fontSize: 12, fontWeight: 600 }}>State bundle</span>
+              <input
+                value={stateBundlePath}
+                onChange={(e) => setStateBundlePath(e.target.value)}
+                placeholder="folder or .zip path"
+                title="Jobs, pipelines, settings, library, collections, saved searches and audit log; no run folders"
+                style={{ minWidth: 340, padding: 6, borderRadius: 6, border: "1px solid #ccc", fontSize: 12 }}
+              />
+              <button
+                onClick={onBackupState}
+                style={{ padding: "4px 8px", borderRadius: 6, border: "1px solid #333", fontSize: 11 }}
+              >
+                Back up state
+              </button>
+              <button
+                onClick={onRestoreState}
+                style={{ padding: "4px 8px", borderRadius: 6, border: "1px solid #333", fontSize: 11 }}
+              >
+                Restore state
+              </button>
+              {stateBundleMessage ? <span style={{ fontSize: 11, opacity: 0.8 }}>{stateBundleMessage}</span> : null}
+            </div>
             {workspaceError ? <div style={{ color: "#c00", fontSize: 12, marginBottom: 6 }}>{workspaceError}</div> : null}
             <div
               style={{